@@ -0,0 +1,215 @@
+use crate::parse::eq_ignore_case;
+use crate::{parse_french_number, write_french_number, Options, ParseError};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+use core::str::FromStr;
+
+/// Suffix distinguishing street numbers sharing the same digits, as in
+/// `12 bis`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AddressSuffix {
+    /// Second building: `bis`.
+    Bis,
+    /// Third building: `ter`.
+    Ter,
+    /// Fourth building: `quater`.
+    Quater,
+    /// Fifth building: `quinquies`.
+    Quinquies,
+    /// Sixth building: `sexies`.
+    Sexies,
+    /// Seventh building: `septies`.
+    Septies,
+    /// Eighth building: `octies`.
+    Octies,
+    /// Ninth building: `nonies`.
+    Nonies,
+    /// Tenth building: `decies`.
+    Decies,
+}
+
+static SUFFIXES: [(AddressSuffix, &str); 9] = [
+    (AddressSuffix::Bis, "bis"),
+    (AddressSuffix::Ter, "ter"),
+    (AddressSuffix::Quater, "quater"),
+    (AddressSuffix::Quinquies, "quinquies"),
+    (AddressSuffix::Sexies, "sexies"),
+    (AddressSuffix::Septies, "septies"),
+    (AddressSuffix::Octies, "octies"),
+    (AddressSuffix::Nonies, "nonies"),
+    (AddressSuffix::Decies, "decies"),
+];
+
+impl AddressSuffix {
+    /// The rank of the building designated by the suffix, from 2 for `bis`
+    /// to 10 for `decies`.
+    #[must_use]
+    pub const fn rank(self) -> u32 {
+        self as u32 + 2
+    }
+
+    /// The suffix designating the building of the given rank, from 2 for
+    /// `bis` to 10 for `decies`.
+    #[must_use]
+    pub fn from_rank(rank: u32) -> Option<Self> {
+        let index = usize::try_from(rank.checked_sub(2)?).ok()?;
+        SUFFIXES.get(index).map(|&(suffix, _)| suffix)
+    }
+
+    /// The suffix as written in addresses, such as `bis`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        SUFFIXES[self as usize].1
+    }
+
+    /// Recognize a suffix, regardless of its case.
+    #[must_use]
+    pub fn from_word(word: &str) -> Option<Self> {
+        SUFFIXES
+            .iter()
+            .find(|(_, name)| eq_ignore_case(word, name))
+            .map(|&(suffix, _)| suffix)
+    }
+}
+
+impl Display for AddressSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A street number, possibly followed by a suffix, as in `12 bis`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StreetNumber {
+    /// The number itself.
+    pub number: u32,
+    /// The suffix, if any.
+    pub suffix: Option<AddressSuffix>,
+}
+
+/// The number is written in digits, as in `12 bis`.
+impl Display for StreetNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number)?;
+        if let Some(suffix) = self.suffix {
+            write!(f, " {suffix}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for StreetNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_french_street_number(s)
+    }
+}
+
+/// Parse a street number written in digits or in words, possibly followed
+/// by a suffix, such as `12 bis`, `12bis` or `douze bis`.
+///
+/// # Errors
+///
+/// An error is returned if the text does not contain a valid number, or if
+/// this number is not strictly positive or does not fit into an `u32`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let twelve_bis = StreetNumber { number: 12, suffix: Some(AddressSuffix::Bis) };
+/// assert_eq!(parse_french_street_number("12 bis"), Ok(twelve_bis));
+/// assert_eq!(parse_french_street_number("12bis"), Ok(twelve_bis));
+/// assert_eq!(parse_french_street_number("douze Bis"), Ok(twelve_bis));
+/// assert_eq!(parse_french_street_number("7"), Ok(StreetNumber { number: 7, suffix: None }));
+/// ```
+pub fn parse_french_street_number(text: &str) -> Result<StreetNumber, ParseError> {
+    let trimmed = text.trim_end();
+    let word_start = trimmed.len()
+        - trimmed
+            .chars()
+            .rev()
+            .take_while(|c| c.is_alphabetic())
+            .map(char::len_utf8)
+            .sum::<usize>();
+    let (number, suffix) = match AddressSuffix::from_word(&trimmed[word_start..]) {
+        Some(suffix) if word_start > 0 => (&trimmed[..word_start], Some(suffix)),
+        _ => (trimmed, None),
+    };
+    let start = number.len() - number.trim_start().len();
+    let number = number.trim();
+    let span = start..start + number.len();
+    let value = if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) {
+        number.parse::<u32>().map_err(|_| ParseError::Overflow)?
+    } else {
+        let value = parse_french_number(number).map_err(|e| match e {
+            ParseError::UnknownWord(r) => ParseError::UnknownWord(r.start + start..r.end + start),
+            ParseError::UnexpectedWord(r) => {
+                ParseError::UnexpectedWord(r.start + start..r.end + start)
+            }
+            e => e,
+        })?;
+        if value < 0 {
+            return Err(ParseError::UnexpectedWord(span));
+        }
+        u32::try_from(value).map_err(|_| ParseError::Overflow)?
+    };
+    if value == 0 {
+        return Err(ParseError::UnexpectedWord(span));
+    }
+    Ok(StreetNumber {
+        number: value,
+        suffix,
+    })
+}
+
+/// Write the French language representation of a street number into a
+/// [`fmt::Write`] sink, as in `douze bis`.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// let number = StreetNumber { number: 3, suffix: Some(AddressSuffix::Quater) };
+/// write_french_street_number(&mut s, &number, &POST_REFORM_MASCULINE).unwrap();
+/// assert_eq!(s, "trois quater");
+/// ```
+pub fn write_french_street_number<W: Write>(
+    w: &mut W,
+    n: &StreetNumber,
+    options: &Options,
+) -> fmt::Result {
+    write_french_number(w, &n.number, options)?;
+    if let Some(suffix) = n.suffix {
+        write!(w, " {suffix}")?;
+    }
+    Ok(())
+}
+
+/// Compute the French language representation of a street number, as in
+/// `douze bis`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let number: StreetNumber = "21ter".parse().unwrap();
+/// assert_eq!(french_street_number(&number, &PRE_REFORM_MASCULINE), "vingt et un ter");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn french_street_number(n: &StreetNumber, options: &Options) -> String {
+    let mut s = String::new();
+    write_french_street_number(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
@@ -0,0 +1,86 @@
+use crate::{french_tokens, Options, Token, TokenKind};
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Write};
+use core::ops::Range;
+use num_integer::Integer;
+use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
+
+/// A word of the French representation of a number, along with the digits
+/// it stands for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WordAlignment {
+    /// The byte range of the word in the French representation.
+    pub text: Range<usize>,
+    /// The positions of the digits the word stands for, in the decimal
+    /// representation of the absolute value of the number, the most
+    /// significant digit being at position 0. This range is empty for the
+    /// sign.
+    pub digits: Range<usize>,
+}
+
+/// Compute the French language representation of the given number with the
+/// given formatting options, along with the digits each word stands for.
+///
+/// Consecutive tokens standing for the same digits, such as `deux-cent` in
+/// `deux-cent-mille`, are reported as a single word, and `et` is reported
+/// along with the word following it, as in `et-onze`. The `mille` word and
+/// the scale nouns stand for their whole group of digits, and are always
+/// reported separately. `None` is returned if
+/// the number is too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let (text, words) = french_alignment(&37_251_061, &POST_REFORM_MASCULINE).unwrap();
+/// let digits = "37251061";
+/// let pairs = words
+///     .iter()
+///     .map(|w| (&text[w.text.clone()], &digits[w.digits.clone()]))
+///     .collect::<Vec<_>>();
+/// assert_eq!(pairs[..4], [("trente", "3"), ("sept", "7"), ("millions", "37"), ("deux-cent", "2")]);
+/// assert_eq!(pairs[6], ("mille", "251"));
+/// ```
+pub fn french_alignment<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+) -> Option<(String, Vec<WordAlignment>)> {
+    french_tokens(n, options).map(|tokens| align_tokens(&tokens))
+}
+
+/// Render the given tokens, and compute the digits each word stands for.
+///
+/// See [`french_alignment`] for details.
+#[must_use]
+pub fn align_tokens(tokens: &[Token]) -> (String, Vec<WordAlignment>) {
+    let mut text = String::new();
+    let mut words: Vec<WordAlignment> = Vec::new();
+    let mut mergeable = false;
+    let mut after_et = false;
+    for token in tokens {
+        text.push_str(token.joiner.as_str());
+        let start = text.len();
+        write!(text, "{token}").expect("writing into a String cannot fail");
+        let merge = mergeable;
+        mergeable = !matches!(
+            token.kind,
+            TokenKind::Sign | TokenKind::Thousand | TokenKind::Scale
+        );
+        match words.last_mut() {
+            Some(last) if after_et => {
+                last.text.end = text.len();
+                last.digits = token.digits.clone();
+            }
+            Some(last) if merge && mergeable && last.digits == token.digits => {
+                last.text.end = text.len();
+            }
+            _ => words.push(WordAlignment {
+                text: start..text.len(),
+                digits: token.digits.clone(),
+            }),
+        }
+        after_et = token.kind == TokenKind::Et;
+    }
+    (text, words)
+}
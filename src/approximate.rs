@@ -0,0 +1,202 @@
+use crate::{write_french_number, Options, Rounding, POST_REFORM_MASCULINE};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Policy for prefixing an approximation with `environ`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EnvironPolicy {
+    /// Never use `environ`: `une centaine`.
+    Never,
+    /// Use `environ` before `un million` or `un milliard` when it differs
+    /// from the approximated value: `environ un million` for 1 200 000, but
+    /// `un million` for 1 000 000. Collective nouns such as `une vingtaine`
+    /// already express an approximation and are used alone.
+    #[default]
+    Inexact,
+    /// Use `environ` before a single quantity which differs from the
+    /// approximated value, collective nouns included: `environ une
+    /// vingtaine` for 23, but `une vingtaine` for 20.
+    InexactCollective,
+    /// Use `environ` before every single quantity: `environ une dizaine`.
+    Always,
+}
+
+/// Options for approximating quantities.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ApproximateOptions {
+    /// Rounding mode used to choose the approximation (default
+    /// [`Rounding::Nearest`]).
+    pub rounding: Rounding,
+    /// Policy for the `environ` prefix (default [`EnvironPolicy::Inexact`]).
+    pub environ: EnvironPolicy,
+    /// Set to `true` to write `plusieurs centaines` instead of
+    /// `des centaines` (default `false`).
+    pub several: bool,
+    /// Options used to spell the numbers too small to be approximated
+    /// (default [`POST_REFORM_MASCULINE`]).
+    pub spelling: Options,
+}
+
+impl Default for ApproximateOptions {
+    fn default() -> Self {
+        Self {
+            rounding: Rounding::Nearest,
+            environ: EnvironPolicy::Inexact,
+            several: false,
+            spelling: POST_REFORM_MASCULINE,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Quantity {
+    // A single collective noun, with its article, which already expresses an
+    // approximation.
+    Collective(&'static str),
+    // A single scale noun, with its article.
+    Single(&'static str),
+    // An undetermined number of units, twice the unit or more.
+    Multiple(&'static str),
+}
+
+static QUANTITIES: [(u128, Quantity); 16] = [
+    (10, Quantity::Collective("une dizaine")),
+    (12, Quantity::Collective("une douzaine")),
+    (15, Quantity::Collective("une quinzaine")),
+    (20, Quantity::Collective("une vingtaine")),
+    (30, Quantity::Collective("une trentaine")),
+    (40, Quantity::Collective("une quarantaine")),
+    (50, Quantity::Collective("une cinquantaine")),
+    (60, Quantity::Collective("une soixantaine")),
+    (100, Quantity::Collective("une centaine")),
+    (200, Quantity::Multiple("centaines")),
+    (1_000, Quantity::Collective("un millier")),
+    (2_000, Quantity::Multiple("milliers")),
+    (1_000_000, Quantity::Single("un million")),
+    (2_000_000, Quantity::Multiple("millions")),
+    (1_000_000_000, Quantity::Single("un milliard")),
+    (2_000_000_000, Quantity::Multiple("milliards")),
+];
+
+// Check whether a value is close enough to the next quantity to round up to
+// it even though it is not the nearest, that is at least 90% of it.
+fn close_to(n: u128, next: u128) -> bool {
+    n >= next - next / 10
+}
+
+// Choose the quantity approximating a number, or `None` if it must be
+// written exactly.
+fn choose(n: u128, rounding: Rounding) -> Option<(u128, Quantity)> {
+    let index = QUANTITIES.partition_point(|&(value, _)| value <= n);
+    let upper = QUANTITIES.get(index).copied();
+    let Some(lower) = index.checked_sub(1).map(|i| QUANTITIES[i]) else {
+        let (value, quantity) = QUANTITIES[0];
+        let round_up = match rounding {
+            Rounding::Nearest => close_to(n, value),
+            Rounding::Down => false,
+            Rounding::Up => n > 0,
+        };
+        return round_up.then_some((value, quantity));
+    };
+    if lower.0 == n {
+        return Some(lower);
+    }
+    match (lower.1, upper) {
+        (Quantity::Multiple(_), Some(upper))
+            if rounding == Rounding::Nearest && close_to(n, upper.0) =>
+        {
+            Some(upper)
+        }
+        (Quantity::Multiple(_), _) | (_, None) => Some(lower),
+        (Quantity::Collective(_) | Quantity::Single(_), Some(upper)) => Some(match rounding {
+            Rounding::Nearest if n - lower.0 < upper.0 - n => lower,
+            Rounding::Down => lower,
+            Rounding::Nearest | Rounding::Up => upper,
+        }),
+    }
+}
+
+/// Write an approximation of a quantity, such as `une vingtaine` or
+/// `des milliers`, into a [`fmt::Write`] sink.
+///
+/// The quantity is approximated by a collective noun (`dizaine`,
+/// `douzaine`, `quinzaine`, `vingtaine`, `trentaine`, `quarantaine`,
+/// `cinquantaine`, `soixantaine`, `centaine` and `millier`) or by `un
+/// million` or `un milliard`, chosen according to the rounding mode. From
+/// twice those units upwards, the quantity is expressed as an undetermined
+/// number of them, as in `des centaines` or `plusieurs millions`, unless it
+/// is within 10% of the next unit and rounded to the nearest. Quantities
+/// smaller than ten which are not rounded to `une dizaine` are spelled
+/// exactly.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_approximate_french_number(&mut s, 23, &ApproximateOptions::default()).unwrap();
+/// assert_eq!(s, "une vingtaine");
+/// s.clear();
+/// write_approximate_french_number(&mut s, 1_200_000, &ApproximateOptions::default()).unwrap();
+/// assert_eq!(s, "environ un million");
+/// ```
+pub fn write_approximate_french_number<W: Write>(
+    w: &mut W,
+    n: u128,
+    options: &ApproximateOptions,
+) -> fmt::Result {
+    match choose(n, options.rounding) {
+        None => write_french_number(w, &n, &options.spelling),
+        Some((value, single @ (Quantity::Collective(quantity) | Quantity::Single(quantity)))) => {
+            let environ = match options.environ {
+                EnvironPolicy::Never => false,
+                EnvironPolicy::Inexact => value != n && matches!(single, Quantity::Single(_)),
+                EnvironPolicy::InexactCollective => value != n,
+                EnvironPolicy::Always => true,
+            };
+            if environ {
+                w.write_str("environ ")?;
+            }
+            w.write_str(quantity)
+        }
+        Some((_, Quantity::Multiple(units))) => {
+            w.write_str(if options.several {
+                "plusieurs "
+            } else {
+                "des "
+            })?;
+            w.write_str(units)
+        }
+    }
+}
+
+/// Compute an approximation of a quantity, such as `une vingtaine` or
+/// `des milliers`.
+///
+/// See [`write_approximate_french_number`] for details.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let options = ApproximateOptions { environ: EnvironPolicy::Never, ..Default::default() };
+/// assert_eq!(approximate_french_number(14, &options), "une quinzaine");
+/// assert_eq!(approximate_french_number(950, &options), "un millier");
+/// assert_eq!(approximate_french_number(4_500, &options), "des milliers");
+/// let options = ApproximateOptions { several: true, ..options };
+/// assert_eq!(approximate_french_number(3_200_000, &options), "plusieurs millions");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn approximate_french_number(n: u128, options: &ApproximateOptions) -> String {
+    let mut s = String::new();
+    write_approximate_french_number(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
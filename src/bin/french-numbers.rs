@@ -1,6 +1,10 @@
 use clap::Parser;
-use french_numbers::{french_number_options, Options};
+use french_numbers::{
+    write_french_digits, write_french_number_io, write_french_number_vigesimal, Options, Vigesimal,
+    FRENCH_DIGITS,
+};
 use num_bigint::BigInt;
+use std::io::{self, BufWriter, Write};
 
 #[derive(Parser)]
 #[clap(version, author, about)]
@@ -9,7 +13,8 @@ struct Args {
     /// Use the feminine declination
     feminine: bool,
     #[clap(short, long)]
-    /// Prefix output with the numerical representation
+    /// Prefix output with the numerical representation, following the
+    /// French typography
     prefix: bool,
     #[clap(short('r'), long)]
     /// Use the pre-1990 orthographic reform writing
@@ -18,6 +23,10 @@ struct Args {
     /// numbers smaller than 100 were separated by dashes, others words
     /// were separated by spaces.
     no_reform: bool,
+    #[clap(long)]
+    /// Use the vigesimal counting of Old and Middle French from 120 to 399,
+    /// as in six-vingts
+    vigesimal: bool,
     /// Number (or low bound) to use
     ///
     /// If no high bound is supplied, this will be the only number displayed.
@@ -27,21 +36,35 @@ struct Args {
 }
 
 // List a single number, or numbers between two bounds given on the command line
-fn main() {
+fn main() -> io::Result<()> {
     let args = Args::parse();
     let options = Options {
         feminine: args.feminine,
         reformed: !args.no_reform,
     };
+    let vigesimal = args.vigesimal.then(Vigesimal::default);
+    let mut words = String::new();
     let high = args.high.unwrap_or_else(|| args.low.clone());
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut prefix = String::new();
     let mut i = args.low;
     while i <= high {
-        let repr = french_number_options(&i, &options);
         if args.prefix {
-            println!("{i} {repr}");
+            prefix.clear();
+            write_french_digits(&mut prefix, &i, &FRENCH_DIGITS)
+                .expect("writing into a String cannot fail");
+            write!(out, "{prefix} ")?;
+        }
+        if let Some(vigesimal) = &vigesimal {
+            words.clear();
+            write_french_number_vigesimal(&mut words, &i, &options, vigesimal)
+                .expect("writing into a String cannot fail");
+            write!(out, "{words}")?;
         } else {
-            println!("{repr}");
+            write_french_number_io(&mut out, &i, &options)?;
         }
+        writeln!(out)?;
         i += 1;
     }
+    out.flush()
 }
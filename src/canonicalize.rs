@@ -0,0 +1,88 @@
+use crate::parse::atoms;
+use crate::{french_number_options, parse_french_number, Options, ParseError};
+use alloc::string::String;
+
+// Capitalisation style of a written number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Capitalized,
+    Title,
+    Upper,
+}
+
+fn case_of(text: &str) -> Case {
+    let starts_upper = |word: &str| word.chars().next().is_some_and(char::is_uppercase);
+    let mut words = atoms(text).map(|a| a.word);
+    if text.chars().any(char::is_alphabetic) && !text.chars().any(char::is_lowercase) {
+        Case::Upper
+    } else if !words.next().is_some_and(starts_upper) {
+        Case::Lower
+    } else if words.clone().next().is_some() && words.all(starts_upper) {
+        Case::Title
+    } else {
+        Case::Capitalized
+    }
+}
+
+fn apply_case(text: &str, case: Case) -> String {
+    match case {
+        Case::Lower => String::from(text),
+        Case::Upper => text.to_uppercase(),
+        Case::Capitalized | Case::Title => {
+            let mut result = String::with_capacity(text.len());
+            let mut word_start = true;
+            for c in text.chars() {
+                if word_start && c.is_alphabetic() {
+                    result.extend(c.to_uppercase());
+                    word_start = false;
+                } else {
+                    result.push(c);
+                    word_start &= !c.is_alphabetic();
+                }
+                if case == Case::Title && (c == '-' || c == ' ') {
+                    word_start = true;
+                }
+            }
+            result
+        }
+    }
+}
+
+/// Rewrite a French number written in any accepted form (before or after
+/// the 1990 reform, with the Belgian and Swiss forms, or any mix of them)
+/// into its canonical form for the given options.
+///
+/// The whitespace surrounding the number is preserved, as well as its
+/// capitalisation: all lowercase, first word capitalized, every word
+/// capitalized, or all uppercase.
+///
+/// # Errors
+///
+/// An error is returned if the text cannot be parsed as a French number.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(canonicalize_french_number(" Deux cent vingt et un\n", &POST_REFORM_MASCULINE),
+///            Ok(String::from(" Deux-cent-vingt-et-un\n")));
+/// assert_eq!(canonicalize_french_number("SEPTANTE-DEUX", &PRE_REFORM_MASCULINE),
+///            Ok(String::from("SOIXANTE-DOUZE")));
+/// ```
+pub fn canonicalize_french_number(text: &str, options: &Options) -> Result<String, ParseError> {
+    let value = parse_french_number(text)?;
+    let mut spans = atoms(text).map(|a| a.span);
+    let first = spans.next().ok_or(ParseError::Empty)?;
+    let (start, end) = (first.start, spans.last().unwrap_or(first).end);
+    let canonical = apply_case(
+        &french_number_options(&value, options),
+        case_of(&text[start..end]),
+    );
+    let mut result = String::with_capacity(text.len() + canonical.len());
+    result.push_str(&text[..start]);
+    result.push_str(&canonical);
+    result.push_str(&text[end..]);
+    Ok(result)
+}
@@ -0,0 +1,224 @@
+use crate::parse::{atoms, eq_ignore_case, Atom};
+use crate::{french_tokens, parse_french_number, render_tokens, Options, ParseError};
+use alloc::{string::String, vec, vec::Vec};
+use core::ops::Range;
+
+/// Kind of spelling mistake found in a French number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// A word is missing its plural mark, as in `quatre-vingt` instead of
+    /// `quatre-vingts`.
+    MissingPlural,
+    /// A word has an invalid plural mark, as in `deux-cents-mille` or
+    /// `milles`.
+    InvalidPlural,
+    /// The `et` conjunction is used where it should not, as in
+    /// `vingt-et-deux`.
+    UnexpectedEt,
+    /// The `et` conjunction is missing, as in `vingt-un`.
+    MissingEt,
+    /// Words are joined by a space instead of a hyphen, or conversely,
+    /// according to the selected orthography.
+    WrongJoiner,
+    /// A word should not be there, as in `un mille`.
+    UnexpectedWord,
+    /// A word is missing.
+    MissingWord,
+    /// Words are misspelled.
+    Misspelling,
+}
+
+/// A spelling mistake found in a French number.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    /// The kind of mistake.
+    pub kind: DiagnosticKind,
+    /// The byte range of the text to replace.
+    pub span: Range<usize>,
+    /// The suggested replacement, computed from the canonical form of the
+    /// number.
+    pub replacement: String,
+}
+
+/// Check the spelling of a French number against its canonical form for the
+/// given options, as computed by [`french_number_options`](crate::french_number_options).
+///
+/// The whole text must be a number, which is parsed leniently with
+/// [`parse_french_number`]. An empty list of diagnostics is returned if the
+/// number is correctly spelled.
+///
+/// # Errors
+///
+/// An error is returned if the text cannot be parsed as a French number.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let text = "quatre-vingt-mille-quatre-vingt";
+/// let diagnostics = check_french_number(text, &POST_REFORM_MASCULINE).unwrap();
+/// assert_eq!(diagnostics, [Diagnostic {
+///     kind: DiagnosticKind::MissingPlural,
+///     span: 26..31,
+///     replacement: String::from("vingts"),
+/// }]);
+/// ```
+pub fn check_french_number(text: &str, options: &Options) -> Result<Vec<Diagnostic>, ParseError> {
+    let value = parse_french_number(text)?;
+    let canonical = render_tokens(&french_tokens(&value, options).ok_or(ParseError::Overflow)?);
+    let written = atoms(text).collect::<Vec<_>>();
+    let expected = atoms(&canonical).collect::<Vec<_>>();
+    let mut diagnostics = Vec::new();
+    // Walk through the hunks located between the words common to both sides,
+    // `None` standing for the end of the text.
+    let mut previous: Option<(usize, usize)> = None;
+    for next in common_words(&written, &expected)
+        .into_iter()
+        .map(Some)
+        .chain([None])
+    {
+        let (wi, ei) = previous.map_or((0, 0), |(i, j)| (i + 1, j + 1));
+        let (wj, ej) = next.unwrap_or((written.len(), expected.len()));
+        let (w, e) = (&written[wi..wj], &expected[ei..ej]);
+        if !w.is_empty() && !e.is_empty() {
+            if previous.is_some() {
+                check_joiner(text, &w[0], &canonical, &e[0], &mut diagnostics);
+            }
+            let span = w[0].span.start..w[w.len() - 1].span.end;
+            let replacement = &canonical[e[0].span.start..e[e.len() - 1].span.end];
+            diagnostics.push(Diagnostic {
+                kind: replacement_kind(&text[span.clone()], replacement, w.len(), e.len()),
+                replacement: match_case(&text[span.clone()], replacement),
+                span,
+            });
+        } else if !w.is_empty() || !e.is_empty() {
+            // Words to insert or remove: replace everything between the surrounding
+            // common words, joiners included.
+            let span = anchors(
+                &written,
+                previous.map(|p| p.0),
+                next.map(|n| n.0),
+                text.len(),
+            );
+            let replacement = anchors(
+                &expected,
+                previous.map(|p| p.1),
+                next.map(|n| n.1),
+                canonical.len(),
+            );
+            let kind = match (w, e) {
+                (w, []) if w.iter().all(|a| eq_ignore_case(a.word, "et")) => {
+                    DiagnosticKind::UnexpectedEt
+                }
+                ([], e) if e.iter().all(|a| a.word == "et") => DiagnosticKind::MissingEt,
+                (_, []) => DiagnosticKind::UnexpectedWord,
+                _ => DiagnosticKind::MissingWord,
+            };
+            diagnostics.push(Diagnostic {
+                kind,
+                span,
+                replacement: String::from(&canonical[replacement]),
+            });
+            previous = next;
+            continue;
+        }
+        if let Some((i, j)) = next.filter(|&(i, j)| i > 0 && j > 0) {
+            check_joiner(
+                text,
+                &written[i],
+                &canonical,
+                &expected[j],
+                &mut diagnostics,
+            );
+        }
+        previous = next;
+    }
+    Ok(diagnostics)
+}
+
+// Find the pairs of indices of the words common to both sides, using the
+// longest common subsequence.
+fn common_words(written: &[Atom], expected: &[Atom]) -> Vec<(usize, usize)> {
+    let (m, n) = (written.len(), expected.len());
+    let mut lengths = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lengths[i][j] = if eq_ignore_case(written[i].word, expected[j].word) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < m && j < n {
+        if eq_ignore_case(written[i].word, expected[j].word) {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+// The range located between the end of the word `before` and the start of the word
+// `after`, or the boundaries of the text.
+fn anchors(
+    atoms: &[Atom],
+    before: Option<usize>,
+    after: Option<usize>,
+    len: usize,
+) -> Range<usize> {
+    let start = before.map_or(atoms.first().map_or(0, |a| a.span.start), |i| {
+        atoms[i].span.end
+    });
+    let end = after.map_or(len, |i| atoms[i].span.start);
+    start..end.max(start)
+}
+
+fn is_hyphen(joiner: &str) -> bool {
+    joiner.contains('-')
+}
+
+fn check_joiner(
+    text: &str,
+    written: &Atom,
+    canonical: &str,
+    expected: &Atom,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let joiner = &canonical[expected.joiner.clone()];
+    if is_hyphen(&text[written.joiner.clone()]) != is_hyphen(joiner) {
+        diagnostics.push(Diagnostic {
+            kind: DiagnosticKind::WrongJoiner,
+            span: written.joiner.clone(),
+            replacement: String::from(joiner),
+        });
+    }
+}
+
+fn replacement_kind(written: &str, expected: &str, w: usize, e: usize) -> DiagnosticKind {
+    let written = written.to_lowercase();
+    if w == 1 && e == 1 && written.strip_suffix('s') == Some(expected) {
+        DiagnosticKind::InvalidPlural
+    } else if w == 1 && e == 1 && expected.strip_suffix('s') == Some(&written) {
+        DiagnosticKind::MissingPlural
+    } else {
+        DiagnosticKind::Misspelling
+    }
+}
+
+// Capitalize the replacement if the written text is capitalized.
+fn match_case(written: &str, replacement: &str) -> String {
+    let mut chars = replacement.chars();
+    match (written.chars().next(), chars.next()) {
+        (Some(w), Some(r)) if w.is_uppercase() => r.to_uppercase().chain(chars).collect(),
+        _ => String::from(replacement),
+    }
+}
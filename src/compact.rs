@@ -0,0 +1,203 @@
+use crate::names;
+use crate::{add_unit_for, write_french_decimal_digits, FRENCH_DIGITS, SCALE_PREFIXES};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Rounding mode used when shortening or approximating a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest value, halfway cases away from zero.
+    #[default]
+    Nearest,
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// Unit following a compact number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactUnit {
+    /// The noun in the singular, such as `euro`.
+    pub singular: &'static str,
+    /// The noun in the plural, such as `euros`.
+    pub plural: &'static str,
+    /// The symbol, such as `€`.
+    pub symbol: &'static str,
+}
+
+/// The euro: `2,5 millions d'euros` or `2,5 M€`.
+pub const EURO: CompactUnit = CompactUnit {
+    singular: names::EURO.0,
+    plural: "euros",
+    symbol: names::EURO.1,
+};
+
+/// The US dollar: `2,5 millions de dollars` or `2,5 M$`.
+pub const DOLLAR: CompactUnit = CompactUnit {
+    singular: names::DOLLAR.0,
+    plural: "dollars",
+    symbol: names::DOLLAR.1,
+};
+
+/// Options for the compact representation of numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompactOptions {
+    /// Set to `true` to use abbreviations (`k`, `M` and `Md`) instead of the
+    /// scale nouns (default `false`). Numbers below one million are written
+    /// in full when the scale nouns are used.
+    pub abbreviated: bool,
+    /// Maximum number of digits after the decimal comma (default `1`).
+    /// Trailing zeros are removed.
+    pub fraction_digits: u32,
+    /// Rounding mode (default [`Rounding::Nearest`]).
+    pub rounding: Rounding,
+    /// Unit following the number (default `None`).
+    pub unit: Option<CompactUnit>,
+}
+
+impl Default for CompactOptions {
+    fn default() -> Self {
+        Self {
+            abbreviated: false,
+            fraction_digits: 1,
+            rounding: Rounding::Nearest,
+            unit: None,
+        }
+    }
+}
+
+static ABBREVIATIONS: [&str; 3] = ["k", "M", "Md"];
+
+// Divide the magnitude by 1000^`log1000` and round it to the requested
+// number of fraction digits, returning the mantissa and the scale of the
+// result, without trailing zeros.
+fn shorten(magnitude: u128, log1000: u32, options: &CompactOptions) -> (u128, u32) {
+    let exponent = 3 * log1000;
+    let mut scale = options.fraction_digits.min(exponent);
+    let divisor = 10u128.pow(exponent - scale);
+    let (quotient, remainder) = (magnitude / divisor, magnitude % divisor);
+    let round_up = match options.rounding {
+        Rounding::Nearest => remainder >= divisor - remainder,
+        Rounding::Down => false,
+        Rounding::Up => remainder > 0,
+    };
+    let mut mantissa = quotient + u128::from(round_up);
+    while scale > 0 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        scale -= 1;
+    }
+    (mantissa, scale)
+}
+
+/// Write the compact representation of a number, such as `1,2 million`, into
+/// a [`fmt::Write`] sink.
+///
+/// The number is expressed in the largest power of thousand it reaches, and
+/// rounded according to the options. The scale noun stays in the singular
+/// below two, as in `1,9 million`, and is followed by `de` before the unit
+/// noun, as in `2,5 millions d'euros`. Abbreviations stop at the `Md`
+/// (milliard), larger numbers being expressed as thousands of milliards.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_compact_french_number(&mut s, 1_940_000, &CompactOptions::default()).unwrap();
+/// assert_eq!(s, "1,9 million");
+/// ```
+pub fn write_compact_french_number<W: Write>(
+    w: &mut W,
+    n: i128,
+    options: &CompactOptions,
+) -> fmt::Result {
+    let magnitude = n.unsigned_abs();
+    let (min, max) = if options.abbreviated {
+        (1, ABBREVIATIONS.len() as u32)
+    } else {
+        (2, 2 * SCALE_PREFIXES.len() as u32 + 1)
+    };
+    let mut log1000 = magnitude.checked_ilog(1000).unwrap_or(0).min(max);
+    if log1000 < min {
+        log1000 = 0;
+    }
+    let (mut mantissa, mut scale) = shorten(magnitude, log1000, options);
+    // Rounding may reach the next power of thousand, as in 999 999.
+    if log1000 < max && log1000 + 1 >= min && mantissa / 10u128.pow(scale) >= 1000 {
+        log1000 += 1;
+        (mantissa, scale) = shorten(magnitude, log1000, options);
+    }
+    let signed = i128::try_from(mantissa).map_err(|_| fmt::Error)?;
+    write_french_decimal_digits(
+        w,
+        if n < 0 { -signed } else { signed },
+        scale,
+        &FRENCH_DIGITS,
+    )?;
+    let plural = mantissa >= 2 * 10u128.pow(scale);
+    if options.abbreviated {
+        if log1000 > 0 || options.unit.is_some() {
+            w.write_char('\u{a0}')?;
+        }
+        if log1000 > 0 {
+            w.write_str(ABBREVIATIONS[log1000 as usize - 1])?;
+        }
+        if let Some(unit) = options.unit {
+            w.write_str(unit.symbol)?;
+        }
+        return Ok(());
+    }
+    if log1000 > 0 {
+        w.write_char(' ')?;
+        add_unit_for(w, if plural { 2 } else { 1 }, log1000 as usize - 2)?;
+    }
+    if let Some(unit) = options.unit {
+        let noun = if plural || log1000 > 0 {
+            unit.plural
+        } else {
+            unit.singular
+        };
+        if log1000 == 0 {
+            w.write_char(' ')?;
+        } else if noun.starts_with(['a', 'e', 'é', 'h', 'i', 'o', 'u']) {
+            w.write_str(" d'")?;
+        } else {
+            w.write_str(" de ")?;
+        }
+        w.write_str(noun)?;
+    }
+    Ok(())
+}
+
+/// Compute the compact representation of a number, such as `1,2 milliard`
+/// or `3 Md€`.
+///
+/// See [`write_compact_french_number`] for details.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let words = CompactOptions { unit: Some(EURO), ..CompactOptions::default() };
+/// assert_eq!(compact_french_number(2_500_000, &words), "2,5 millions d'euros");
+/// assert_eq!(compact_french_number(1_234_567_890, &words), "1,2 milliard d'euros");
+/// let abbreviated = CompactOptions { abbreviated: true, ..words };
+/// assert_eq!(compact_french_number(3_000_000_000, &abbreviated), "3\u{a0}Md€");
+/// assert_eq!(compact_french_number(15_000, &CompactOptions { abbreviated: true, ..Default::default() }),
+///            "15\u{a0}k");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn compact_french_number(n: i128, options: &CompactOptions) -> String {
+    let mut s = String::new();
+    write_compact_french_number(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
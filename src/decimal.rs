@@ -0,0 +1,309 @@
+use crate::{write_french_decimal_digits, write_french_number, Options, FRENCH_DIGITS};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// An exact decimal number, equal to `mantissa` × 10^-`scale`.
+///
+/// The scale records the number of digits written after the decimal comma,
+/// so that `1,50` and `1,5` are distinct values which are spelled
+/// differently.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// The digits of the number, with its sign.
+    pub mantissa: i128,
+    /// The number of digits following the decimal comma.
+    pub scale: u32,
+}
+
+impl Decimal {
+    /// Build a decimal number equal to `mantissa` × 10^-`scale`.
+    #[must_use]
+    pub const fn new(mantissa: i128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    // Split the magnitude of the number into its integral and fractional
+    // parts.
+    fn parts(&self) -> (u128, u128) {
+        let magnitude = self.mantissa.unsigned_abs();
+        match 10u128.checked_pow(self.scale) {
+            Some(divisor) => (magnitude / divisor, magnitude % divisor),
+            None => (0, magnitude),
+        }
+    }
+
+    /// The value of the number if it is an integer.
+    #[must_use]
+    pub fn to_integer(&self) -> Option<i128> {
+        let divisor = 10i128.checked_pow(self.scale)?;
+        (self.mantissa % divisor == 0).then(|| self.mantissa / divisor)
+    }
+}
+
+impl From<i128> for Decimal {
+    fn from(n: i128) -> Self {
+        Self::new(n, 0)
+    }
+}
+
+/// The number is written in digits following the French typography, as
+/// with [`FRENCH_DIGITS`].
+impl Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_french_decimal_digits(f, self.mantissa, self.scale, &FRENCH_DIGITS)
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = NumeralError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_french_numeral(s)
+    }
+}
+
+/// Error returned when parsing a number written in digits fails.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NumeralError {
+    /// The text does not contain any digit.
+    Empty,
+    /// The character at the given byte offset cannot appear at this place.
+    InvalidCharacter(usize),
+    /// The group of digits at the given byte range does not have the right
+    /// number of digits.
+    InvalidGrouping(Range<usize>),
+    /// The number is too large to be represented.
+    Overflow,
+}
+
+impl Display for NumeralError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no number found"),
+            Self::InvalidCharacter(pos) => write!(f, "invalid character at {pos}"),
+            Self::InvalidGrouping(span) => write!(f, "invalid group of digits at {span:?}"),
+            Self::Overflow => write!(f, "number too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumeralError {}
+
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}' | '\u{202f}' | '\u{2009}')
+}
+
+// The kind of a group separator, all spaces being equivalent.
+fn separator_kind(c: char) -> Option<char> {
+    match c {
+        c if is_space(c) => Some(' '),
+        '.' => Some('.'),
+        '\'' | '’' => Some('\''),
+        _ => None,
+    }
+}
+
+// Find the decimal separator of a number: the comma if there is one, else
+// the dot unless it is used to group digits by three.
+fn decimal_separator(text: &str) -> Option<char> {
+    if text.contains(',') {
+        return Some(',');
+    }
+    if text.contains(['\'', '’']) {
+        return Some('.');
+    }
+    let mut segments = text.split('.').skip(1).peekable();
+    segments.peek()?;
+    let grouping = segments.all(|s| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()) == 3);
+    (!grouping).then_some('.')
+}
+
+// Accumulate the digits of a part of a number, checking that its groups have
+// three digits. The first group of the integral part may be shorter, as well
+// as the last group of the fractional part.
+fn accumulate(
+    text: &str,
+    offset: usize,
+    integral: bool,
+    separators: &[char],
+    (mantissa, scale): (&mut i128, &mut u32),
+) -> Result<(), NumeralError> {
+    let mut kind = None;
+    let mut group_start = offset;
+    let mut groups = 0;
+    for (i, c) in text.char_indices() {
+        let pos = offset + i;
+        if let Some(digit) = c.to_digit(10) {
+            *mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(digit)))
+                .ok_or(NumeralError::Overflow)?;
+            *scale += u32::from(!integral);
+            continue;
+        }
+        let separator = separator_kind(c)
+            .filter(|s| separators.contains(s) && kind.is_none_or(|k| k == *s))
+            .filter(|_| pos > group_start)
+            .ok_or(NumeralError::InvalidCharacter(pos))?;
+        let len = pos - group_start;
+        if !(len == 3 || integral && groups == 0 && len < 3) {
+            return Err(NumeralError::InvalidGrouping(group_start..pos));
+        }
+        kind = Some(separator);
+        groups += 1;
+        group_start = pos + c.len_utf8();
+    }
+    let end = offset + text.len();
+    let len = end - group_start;
+    if len == 0 {
+        return Err(if groups == 0 {
+            NumeralError::Empty
+        } else {
+            NumeralError::InvalidCharacter(group_start - 1)
+        });
+    }
+    if groups > 0 && !(len == 3 || !integral && len < 3) {
+        return Err(NumeralError::InvalidGrouping(group_start..end));
+    }
+    Ok(())
+}
+
+/// Parse a number written in digits in the French, Belgian or Swiss way.
+///
+/// Groups of three digits may be separated by spaces (regular, no-break,
+/// narrow no-break or thin), dots or apostrophes, and the decimal separator
+/// is the comma. The dot is accepted as a decimal separator as well when it
+/// is not used to group digits, as in the Swiss `1'234.56`. Digits following
+/// the decimal separator may be grouped by three with spaces. A leading
+/// `-`, `−` or `+` sign and surrounding whitespace are accepted.
+///
+/// # Errors
+///
+/// An error is returned if the text is not a well-formed number, or if its
+/// digits do not fit into an `i128`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(parse_french_numeral("1\u{202f}234,56"), Ok(Decimal::new(123_456, 2)));
+/// assert_eq!(parse_french_numeral("1.234,56"), Ok(Decimal::new(123_456, 2)));
+/// assert_eq!(parse_french_numeral("1'234.56"), Ok(Decimal::new(123_456, 2)));
+/// assert_eq!(parse_french_numeral("-3,141 59"), Ok(Decimal::new(-314_159, 5)));
+/// assert_eq!(parse_french_numeral("12 34"), Err(NumeralError::InvalidGrouping(3..5)));
+/// ```
+pub fn parse_french_numeral(text: &str) -> Result<Decimal, NumeralError> {
+    let offset = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    let (negative, body) = match trimmed.chars().next() {
+        Some(c @ ('-' | '−' | '+')) => (c != '+', &trimmed[c.len_utf8()..]),
+        Some(_) => (false, trimmed),
+        None => return Err(NumeralError::Empty),
+    };
+    let offset = offset + trimmed.len() - body.len();
+    let decimal = decimal_separator(body);
+    let (integral, fractional) = match decimal.and_then(|d| body.split_once(d)) {
+        Some((integral, fractional)) => (integral, Some(fractional)),
+        None => (body, None),
+    };
+    let group_separators: &[char] = match decimal {
+        Some('.') => &[' ', '\''],
+        _ => &[' ', '.', '\''],
+    };
+    let mut mantissa = 0;
+    let mut scale = 0;
+    accumulate(
+        integral,
+        offset,
+        true,
+        group_separators,
+        (&mut mantissa, &mut scale),
+    )?;
+    if let Some(fractional) = fractional {
+        let start = offset + integral.len() + 1;
+        accumulate(
+            fractional,
+            start,
+            false,
+            &[' '],
+            (&mut mantissa, &mut scale),
+        )
+        .map_err(|e| match e {
+            NumeralError::Empty => NumeralError::InvalidCharacter(start - 1),
+            e => e,
+        })?;
+    }
+    Ok(Decimal::new(
+        if negative { -mantissa } else { mantissa },
+        scale,
+    ))
+}
+
+/// Write the French language representation of a decimal number into a
+/// [`fmt::Write`] sink.
+///
+/// The integral part is spelled with the given options, followed by
+/// `virgule` and by the digits following the decimal comma: their leading
+/// zeros are spelled one by one, and the remaining digits as a masculine
+/// number.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_french_decimal_number(&mut s, &Decimal::new(-1205, 3), &POST_REFORM_MASCULINE).unwrap();
+/// assert_eq!(s, "moins un virgule deux-cent-cinq");
+/// ```
+pub fn write_french_decimal_number<W: Write>(
+    w: &mut W,
+    n: &Decimal,
+    options: &Options,
+) -> fmt::Result {
+    let (integral, fractional) = n.parts();
+    if n.mantissa < 0 {
+        w.write_str("moins ")?;
+    }
+    write_french_number(w, &integral, options)?;
+    if n.scale > 0 {
+        w.write_str(" virgule")?;
+        let digits = fractional.checked_ilog10().map_or(0, |l| l + 1);
+        for _ in digits..n.scale {
+            w.write_str(" zéro")?;
+        }
+        if fractional > 0 {
+            w.write_char(' ')?;
+            write_french_number(w, &fractional, &options.masculinize())?;
+        }
+    }
+    Ok(())
+}
+
+/// Compute the French language representation of a decimal number.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let n = parse_french_numeral("1 234,05").unwrap();
+/// assert_eq!(french_decimal_number(&n, &PRE_REFORM_MASCULINE),
+///            "mille deux cent trente-quatre virgule zéro cinq");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn french_decimal_number(n: &Decimal, options: &Options) -> String {
+    let mut s = String::new();
+    write_french_decimal_number(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
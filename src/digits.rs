@@ -0,0 +1,258 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Display, Write};
+use num_integer::Integer;
+
+/// Options for writing numbers in digits following the French typography.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DigitsOptions {
+    /// Separator inserted between groups of three digits of the integral
+    /// part, or `None` to disable grouping (default U+202F NARROW NO-BREAK
+    /// SPACE).
+    pub group_separator: Option<char>,
+    /// Minimum number of digits of the integral part for the grouping to
+    /// take place (default `4`). Use `5` to leave four-digit numbers such as
+    /// years ungrouped.
+    pub min_grouped_digits: usize,
+    /// Unit written after the number, such as `%` or `€` (default `None`).
+    pub unit: Option<&'static str>,
+    /// Separator inserted before the unit, or `None` to stick the unit to
+    /// the number (default U+00A0 NO-BREAK SPACE).
+    pub unit_separator: Option<char>,
+}
+
+/// French typography: `1 234 567,89`, with narrow no-break spaces between
+/// groups of digits. This is the default.
+pub const FRENCH_DIGITS: DigitsOptions = DigitsOptions {
+    group_separator: Some('\u{202f}'),
+    min_grouped_digits: 4,
+    unit: None,
+    unit_separator: Some('\u{a0}'),
+};
+
+/// French typography for years, which are not grouped when they have four
+/// digits: `2026`.
+pub const YEAR_DIGITS: DigitsOptions = DigitsOptions {
+    min_grouped_digits: 5,
+    ..FRENCH_DIGITS
+};
+
+/// French typography for percentages: `12,5 %`.
+pub const PERCENT_DIGITS: DigitsOptions = DigitsOptions {
+    unit: Some("%"),
+    ..FRENCH_DIGITS
+};
+
+/// French typography for amounts in euros: `1 234,50 €`.
+pub const EURO_DIGITS: DigitsOptions = DigitsOptions {
+    unit: Some("€"),
+    ..FRENCH_DIGITS
+};
+
+impl Default for DigitsOptions {
+    fn default() -> Self {
+        FRENCH_DIGITS
+    }
+}
+
+// Insert the group separator between the digits written through it, knowing
+// the total number of digits.
+struct Grouping<'a, W> {
+    inner: &'a mut W,
+    separator: Option<char>,
+    remaining: usize,
+}
+
+impl<W: Write> Write for Grouping<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !c.is_ascii_digit() {
+                continue;
+            }
+            self.inner.write_char(c)?;
+            self.remaining -= 1;
+            if self.remaining > 0 && self.remaining % 3 == 0 {
+                if let Some(separator) = self.separator {
+                    self.inner.write_char(separator)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Count the digits written through it.
+struct DigitCounter(usize);
+
+impl Write for DigitCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().filter(char::is_ascii_digit).count();
+        Ok(())
+    }
+}
+
+// Detect whether the representation of a number starts with a minus sign.
+struct SignDetector(Option<bool>);
+
+impl Write for SignDetector {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.0.is_none() {
+            self.0 = s.chars().next().map(|c| c == '-');
+        }
+        Ok(())
+    }
+}
+
+// Write the integral part of a number, given by its decimal representation.
+fn write_integral<W: Write>(
+    w: &mut W,
+    integral: &dyn Display,
+    options: &DigitsOptions,
+) -> fmt::Result {
+    let mut counter = DigitCounter(0);
+    write!(counter, "{integral}")?;
+    let separator = options
+        .group_separator
+        .filter(|_| counter.0 >= options.min_grouped_digits);
+    write!(
+        Grouping {
+            inner: w,
+            separator,
+            remaining: counter.0,
+        },
+        "{integral}"
+    )
+}
+
+fn write_unit<W: Write>(w: &mut W, options: &DigitsOptions) -> fmt::Result {
+    if let Some(unit) = options.unit {
+        if let Some(separator) = options.unit_separator {
+            w.write_char(separator)?;
+        }
+        w.write_str(unit)?;
+    }
+    Ok(())
+}
+
+/// Write an integer in digits following the French typography into a
+/// [`fmt::Write`] sink.
+///
+/// Any integer type whose [`Display`] implementation writes its decimal
+/// representation may be used, such as the primitive integers or
+/// `num_bigint::BigInt`. Negative numbers are written with a leading `-`
+/// sign. Floating-point numbers are not accepted, use
+/// [`write_french_decimal_digits`] instead:
+///
+/// ```compile_fail
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_french_digits(&mut s, &1234.5, &FRENCH_DIGITS).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_french_digits(&mut s, &-1_234_567, &FRENCH_DIGITS).unwrap();
+/// assert_eq!(s, "-1\u{202f}234\u{202f}567");
+/// ```
+pub fn write_french_digits<W: Write, N: Integer + Display>(
+    w: &mut W,
+    n: &N,
+    options: &DigitsOptions,
+) -> fmt::Result {
+    let mut sign = SignDetector(None);
+    write!(sign, "{n}")?;
+    if sign.0 == Some(true) {
+        w.write_char('-')?;
+    }
+    write_integral(w, n, options)?;
+    write_unit(w, options)
+}
+
+/// Write a decimal number in digits following the French typography into a
+/// [`fmt::Write`] sink.
+///
+/// The number is given by its digits, as an integer, and by the number of
+/// those digits that follow the decimal comma: `mantissa` × 10^-`scale`.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_french_decimal_digits(&mut s, 123_456_789, 2, &EURO_DIGITS).unwrap();
+/// assert_eq!(s, "1\u{202f}234\u{202f}567,89\u{a0}€");
+/// ```
+pub fn write_french_decimal_digits<W: Write>(
+    w: &mut W,
+    mantissa: i128,
+    scale: u32,
+    options: &DigitsOptions,
+) -> fmt::Result {
+    if mantissa < 0 {
+        w.write_char('-')?;
+    }
+    let magnitude = mantissa.unsigned_abs();
+    let (integral, fractional) = match 10u128.checked_pow(scale) {
+        Some(divisor) => (magnitude / divisor, magnitude % divisor),
+        None => (0, magnitude),
+    };
+    write_integral(w, &integral, options)?;
+    if scale > 0 {
+        write!(w, ",{fractional:0width$}", width = scale as usize)?;
+    }
+    write_unit(w, options)
+}
+
+/// Compute the representation in digits of an integer following the French
+/// typography.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(french_digits(&1_234_567, &FRENCH_DIGITS), "1\u{202f}234\u{202f}567");
+/// assert_eq!(french_digits(&2026, &YEAR_DIGITS), "2026");
+/// assert_eq!(french_digits(&12, &PERCENT_DIGITS), "12\u{a0}%");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn french_digits<N: Integer + Display>(n: &N, options: &DigitsOptions) -> String {
+    let mut s = String::new();
+    write_french_digits(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
+
+/// Compute the representation in digits of a decimal number following the
+/// French typography, the number being `mantissa` × 10^-`scale`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(french_decimal_digits(-125, 1, &PERCENT_DIGITS), "-12,5\u{a0}%");
+/// assert_eq!(french_decimal_digits(5, 3, &FRENCH_DIGITS), "0,005");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn french_decimal_digits(mantissa: i128, scale: u32, options: &DigitsOptions) -> String {
+    let mut s = String::new();
+    write_french_decimal_digits(&mut s, mantissa, scale, options)
+        .expect("writing into a String cannot fail");
+    s
+}
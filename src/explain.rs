@@ -0,0 +1,229 @@
+use crate::{french_tokens, Joiner, Options, Token, TokenKind};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Write};
+use core::ops::Range;
+use num_integer::Integer;
+use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
+
+/// Orthographic rule applied when spelling a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// `vingt` takes an `s` in `quatre-vingts` when it ends the number or
+    /// is followed by a scale noun.
+    QuatreVingtsPlural,
+    /// `vingt` is invariable in `quatre-vingt` when followed by another
+    /// number.
+    QuatreVingtFollowed,
+    /// `vingt` is invariable in `quatre-vingt` when followed by `mille`.
+    QuatreVingtBeforeMille,
+    /// `et` is not used in `quatre-vingt-un` and `quatre-vingt-onze`.
+    NoEtAfterQuatreVingt,
+    /// `cent` takes an `s` when multiplied and ending the number or
+    /// followed by a scale noun.
+    CentPlural,
+    /// `cent` is invariable when followed by another number.
+    CentFollowed,
+    /// `cent` is invariable when followed by `mille`.
+    CentBeforeMille,
+    /// `cent` alone is not preceded by `un`.
+    NoUnBeforeCent,
+    /// `mille` is invariable.
+    MilleInvariable,
+    /// `mille` alone is not preceded by `un`.
+    NoUnBeforeMille,
+    /// Scale nouns such as `million` take an `s` when multiplied.
+    ScalePlural,
+    /// Scale nouns such as `million` are preceded by `un` when alone.
+    UnBeforeScale,
+    /// `et` is inserted in 21, 31, 41, 51, 61 and 71.
+    Et,
+    /// `un` becomes `une` in the feminine declination.
+    Feminine,
+    /// Since the 1990 reform, hyphens join all the words.
+    ReformHyphens,
+    /// Before the 1990 reform, hyphens only join words below one hundred
+    /// which are not joined by `et`.
+    TraditionalHyphens,
+}
+
+impl Rule {
+    /// A short English description of the rule.
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::QuatreVingtsPlural => {
+                "vingt takes an s in quatre-vingts because it ends the number or precedes a scale noun"
+            }
+            Self::QuatreVingtFollowed => {
+                "vingt is invariable in quatre-vingt because it is followed by another number"
+            }
+            Self::QuatreVingtBeforeMille => {
+                "vingt is invariable in quatre-vingt because it is followed by mille"
+            }
+            Self::NoEtAfterQuatreVingt => "et is not used in 81 and 91",
+            Self::CentPlural => {
+                "cent takes an s because it is multiplied and ends the number or precedes a scale noun"
+            }
+            Self::CentFollowed => "cent is invariable because it is followed by another number",
+            Self::CentBeforeMille => "cent is invariable because it is followed by mille",
+            Self::NoUnBeforeCent => "cent is not preceded by un",
+            Self::MilleInvariable => "mille is invariable",
+            Self::NoUnBeforeMille => "mille is not preceded by un",
+            Self::ScalePlural => "scale nouns such as million take an s when multiplied",
+            Self::UnBeforeScale => "scale nouns such as million are preceded by un",
+            Self::Et => "et is inserted in 21, 31, 41, 51, 61 and 71",
+            Self::Feminine => "un becomes une in the feminine",
+            Self::ReformHyphens => "1990 reform: hyphens everywhere",
+            Self::TraditionalHyphens => {
+                "before the 1990 reform: hyphens only between words below one hundred, except around et"
+            }
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// An orthographic rule applied when spelling a number, along with the part
+/// of the French representation it applies to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Explanation {
+    /// The rule.
+    pub rule: Rule,
+    /// The byte range of the French representation the rule applies to.
+    pub text: Range<usize>,
+}
+
+/// Compute the French language representation of the given number with the
+/// given formatting options, along with the orthographic rules it triggered.
+///
+/// The explanations follow the order of the words, a word being explained
+/// by several rules when several apply to it, as `une` in
+/// `quatre-vingt-une`.
+///
+/// `None` is returned if the number is too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let (text, explanations) = explain_french_number(&80_021, &POST_REFORM_MASCULINE).unwrap();
+/// assert_eq!(text, "quatre-vingt-mille-vingt-et-un");
+/// let rules = explanations.iter().map(|e| (e.rule, &text[e.text.clone()])).collect::<Vec<_>>();
+/// assert_eq!(rules, [
+///     (Rule::QuatreVingtBeforeMille, "quatre-vingt"),
+///     (Rule::MilleInvariable, "mille"),
+///     (Rule::Et, "et"),
+///     (Rule::ReformHyphens, "quatre-vingt-mille-vingt-et-un"),
+/// ]);
+/// ```
+pub fn explain_french_number<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+) -> Option<(String, Vec<Explanation>)> {
+    let tokens = french_tokens(n, options)?;
+    let mut text = String::new();
+    let mut ranges = Vec::with_capacity(tokens.len());
+    for token in &tokens {
+        text.push_str(token.joiner.as_str());
+        let start = text.len();
+        write!(text, "{token}").expect("writing into a String cannot fail");
+        ranges.push(start..text.len());
+    }
+    let mut explanations = Vec::new();
+    for (i, range) in ranges.iter().enumerate() {
+        if let Some(rule) = token_rule(&tokens, i) {
+            explanations.push(Explanation {
+                rule,
+                text: range.clone(),
+            });
+        }
+        // The feminine may come on top of another rule, as in
+        // `quatre-vingt-une`.
+        if tokens[i].kind == TokenKind::Unit && tokens[i].to_string() == "une" {
+            explanations.push(Explanation {
+                rule: Rule::Feminine,
+                text: range.clone(),
+            });
+        }
+    }
+    // The hyphenation rule applies to the whole number, without its sign.
+    let first = usize::from(tokens[0].kind == TokenKind::Sign);
+    let words = &tokens[first..];
+    let rule = if options.reformed {
+        words[1..]
+            .iter()
+            .any(|t| t.joiner == Joiner::Hyphen)
+            .then_some(Rule::ReformHyphens)
+    } else {
+        words[1..]
+            .iter()
+            .any(|t| t.joiner == Joiner::Space)
+            .then_some(Rule::TraditionalHyphens)
+    };
+    if let Some(rule) = rule {
+        explanations.push(Explanation {
+            rule,
+            text: ranges[first].start..text.len(),
+        });
+    }
+    Some((text, explanations))
+}
+
+fn token_rule(tokens: &[Token], i: usize) -> Option<Rule> {
+    let token = &tokens[i];
+    let previous = i.checked_sub(1).map(|i| &tokens[i]);
+    let next = tokens.get(i + 1);
+    let next_is_mille = next.is_some_and(|t| t.kind == TokenKind::Thousand);
+    let word = token.to_string();
+    match token.kind {
+        TokenKind::Tens if word.starts_with("quatre-vingt") => Some(if token.is_plural() {
+            Rule::QuatreVingtsPlural
+        } else if next_is_mille {
+            Rule::QuatreVingtBeforeMille
+        } else {
+            Rule::QuatreVingtFollowed
+        }),
+        TokenKind::Unit | TokenKind::Teen
+            if matches!(&*word, "un" | "une" | "onze")
+                && token.joiner == Joiner::Hyphen
+                && previous.is_some_and(|p| p.to_string() == "quatre-vingt") =>
+        {
+            Some(Rule::NoEtAfterQuatreVingt)
+        }
+        TokenKind::Hundred => {
+            let multiplied =
+                previous.is_some_and(|p| p.kind == TokenKind::Unit && p.digits == token.digits);
+            Some(if !multiplied {
+                Rule::NoUnBeforeCent
+            } else if token.is_plural() {
+                Rule::CentPlural
+            } else if next_is_mille {
+                Rule::CentBeforeMille
+            } else {
+                Rule::CentFollowed
+            })
+        }
+        TokenKind::Thousand => Some(
+            if previous.is_some_and(|p| !matches!(p.kind, TokenKind::Sign | TokenKind::Scale)) {
+                Rule::MilleInvariable
+            } else {
+                Rule::NoUnBeforeMille
+            },
+        ),
+        TokenKind::Scale => Some(if token.is_plural() {
+            Rule::ScalePlural
+        } else {
+            Rule::UnBeforeScale
+        }),
+        TokenKind::Et => Some(Rule::Et),
+        _ => None,
+    }
+}
@@ -0,0 +1,321 @@
+use crate::names::{Currency, DOLLAR, EURO, FRANC, MONTHS};
+use crate::parse::{eq_ignore_case, text_words};
+use crate::{
+    extract_french_numbers, write_french_decimal_digits, DigitsOptions, ExtractedNumber,
+    NumberKind, Options, FRENCH_DIGITS,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Position of the currency symbol relative to the amount.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CurrencyPlacement {
+    /// The symbol precedes the amount, as in `€250`.
+    Before,
+    /// The symbol follows the amount after a no-break space, as in `250 €`.
+    #[default]
+    After,
+}
+
+/// Options for writing numbers in digits in a French text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InverseOptions {
+    /// Separator inserted between groups of three digits in numbers of at
+    /// least four digits, or `None` to disable grouping (default U+202F
+    /// NARROW NO-BREAK SPACE). Years are never grouped.
+    pub group_separator: Option<char>,
+    /// Position of the currency symbol (default [`CurrencyPlacement::After`]).
+    pub currency_placement: CurrencyPlacement,
+    /// Cardinal and ordinal numbers whose absolute value is strictly
+    /// smaller than this threshold are left in words (default `10`). Dates,
+    /// amounts, percentages and decimal numbers are always written in
+    /// digits.
+    pub threshold: u128,
+}
+
+impl Default for InverseOptions {
+    fn default() -> Self {
+        Self {
+            group_separator: Some('\u{202f}'),
+            currency_placement: CurrencyPlacement::After,
+            threshold: 10,
+        }
+    }
+}
+
+// The currencies named in words, leaving out `livre` which names a book as
+// often as a pound.
+static CURRENCIES: [Currency; 3] = [EURO, DOLLAR, FRANC];
+
+/// Rewrite the numbers spelled in words in a French text into digits, as
+/// expected when displaying the output of a speech recognizer.
+///
+/// Numbers are written with the decimal comma and, depending on the options,
+/// with their digits grouped by three. Amounts in euros, dollars and francs
+/// use the currency symbol, `douze euros cinquante` becoming `12,50 €` and
+/// `douze dollars et cinquante cents` becoming `12,50 $`, and percentages
+/// use the `%` sign. Days followed by a month are always written
+/// in digits, `premier` becoming `1er`, as well as the year that may follow.
+///
+/// Numbers smaller than the threshold of the options are left in words, as
+/// well as fractions such as `trois quarts`. The articles `un` and `une`
+/// are not mistaken for numbers, except before a currency name as in
+/// `un euro`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(
+///     inverse_normalize_french_text(
+///         "il a payé deux-cent-cinquante euros le vingt-et-un mars pour trois places",
+///         &InverseOptions::default()),
+///     "il a payé 250\u{a0}€ le 21 mars pour trois places");
+/// assert_eq!(
+///     inverse_normalize_french_text(
+///         "douze mille personnes et cinq chats",
+///         &InverseOptions { group_separator: Some(' '), threshold: 0, ..InverseOptions::default() }),
+///     "12 000 personnes et 5 chats");
+/// ```
+#[must_use]
+pub fn inverse_normalize_french_text(text: &str, options: &InverseOptions) -> String {
+    let mut numbers = extract_french_numbers(text)
+        .into_iter()
+        .filter(|n| n.options.is_some())
+        .collect::<Vec<_>>();
+    // The article in `un euro` is not reported by the extraction, although it
+    // stands for an amount here.
+    for word in text_words(text) {
+        if (eq_ignore_case(word.word, "un") || eq_ignore_case(word.word, "une"))
+            && currency_after(text, word.span.end).is_some()
+            && !numbers.iter().any(|n| n.span.contains(&word.span.start))
+        {
+            numbers.push(ExtractedNumber {
+                span: word.span,
+                kind: NumberKind::Cardinal,
+                numerator: 1,
+                denominator: 1,
+                options: Some(Options::default()),
+            });
+        }
+    }
+    numbers.sort_unstable_by_key(|n| n.span.start);
+    let mut replacements = Vec::new();
+    let mut end = 0;
+    for (i, number) in numbers.iter().enumerate() {
+        if number.span.start < end {
+            continue;
+        }
+        if let Some((span, digits)) =
+            amount(text, &numbers[i..], options).or_else(|| rewrite(text, number, options))
+        {
+            end = span.end;
+            replacements.push((span, digits));
+        }
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut pos = 0;
+    for (span, digits) in replacements {
+        result.push_str(&text[pos..span.start]);
+        result.push_str(&digits);
+        pos = span.end;
+    }
+    result.push_str(&text[pos..]);
+    result
+}
+
+// Return the word following the given position after some whitespace, along
+// with its end.
+fn next_word(text: &str, pos: usize) -> Option<(&str, usize)> {
+    let start = pos + text[pos..].find(|c: char| !c.is_whitespace())?;
+    if start == pos {
+        return None;
+    }
+    let end = text[start..]
+        .find(|c: char| !c.is_alphabetic())
+        .map_or(text.len(), |i| start + i);
+    (end > start).then(|| (&text[start..end], end))
+}
+
+// Return the symbol of the currency whose hundredth is named by the given
+// word, such as `centimes` or `cents`.
+fn hundredth_symbol(word: &str) -> Option<&'static str> {
+    let singular = word.strip_suffix('s').unwrap_or(word);
+    CURRENCIES
+        .iter()
+        .find(|(_, _, hundredth)| eq_ignore_case(singular, hundredth))
+        .map(|&(_, symbol, _)| symbol)
+}
+
+// Return the currency symbol named right after the given position, possibly
+// preceded by `de` as in `deux millions d'euros`, along with the end of its
+// name.
+fn currency_after(text: &str, pos: usize) -> Option<(&'static str, usize)> {
+    let mut start = pos + text[pos..].find(|c: char| !c.is_whitespace())?;
+    if start == pos {
+        return None;
+    }
+    if let Some(de) = ["d'", "d’", "de "]
+        .iter()
+        .find(|de| text[start..].starts_with(*de))
+    {
+        start += de.len();
+    }
+    let end = text[start..]
+        .find(|c: char| !c.is_alphabetic())
+        .map_or(text.len(), |i| start + i);
+    let word = &text[start..end];
+    let singular = word.strip_suffix('s').unwrap_or(word);
+    CURRENCIES
+        .iter()
+        .find(|(name, _, _)| eq_ignore_case(word, name) || eq_ignore_case(singular, name))
+        .map(|&(_, symbol, _)| (symbol, end))
+}
+
+// Write a number, possibly decimal, with the decimal comma and its digits
+// grouped as requested.
+fn push_number(out: &mut String, number: &ExtractedNumber, options: &DigitsOptions) {
+    let scale = number.denominator.ilog10();
+    write_french_decimal_digits(out, number.numerator, scale, options)
+        .expect("writing into a String cannot fail");
+}
+
+fn digits_options(options: &InverseOptions) -> DigitsOptions {
+    DigitsOptions {
+        group_separator: options.group_separator,
+        ..FRENCH_DIGITS
+    }
+}
+
+fn push_amount(out: &mut String, amount: &str, symbol: &str, options: &InverseOptions) {
+    match options.currency_placement {
+        CurrencyPlacement::Before => {
+            out.push_str(symbol);
+            out.push_str(amount);
+        }
+        CurrencyPlacement::After => {
+            out.push_str(amount);
+            out.push('\u{a0}');
+            out.push_str(symbol);
+        }
+    }
+}
+
+// Rewrite an amount such as `douze euros cinquante`, made of the first
+// number and possibly of the next one.
+fn amount(
+    text: &str,
+    numbers: &[ExtractedNumber],
+    options: &InverseOptions,
+) -> Option<(Range<usize>, String)> {
+    let number = &numbers[0];
+    if !matches!(number.kind, NumberKind::Cardinal | NumberKind::Decimal) {
+        return None;
+    }
+    // The hundredth is matched before the number words, so that `cents` is
+    // not read as a number, unless a currency is named next as in `vingt
+    // cents dollars`.
+    if let Some((symbol, end)) = next_word(text, number.span.end)
+        .and_then(|(word, end)| Some((hundredth_symbol(word)?, end)))
+        .filter(|&(_, end)| currency_after(text, end).is_none())
+    {
+        if number.kind != NumberKind::Cardinal || !(1..100).contains(&number.numerator) {
+            return None;
+        }
+        let mut digits = String::new();
+        push_amount(
+            &mut digits,
+            &alloc::format!("0,{:02}", number.numerator),
+            symbol,
+            options,
+        );
+        return Some((number.span.start..end, digits));
+    }
+    let (symbol, mut end) = currency_after(text, number.span.end)?;
+    let mut digits = String::new();
+    push_number(&mut digits, number, &digits_options(options));
+    if let Some(cents) = numbers.get(1).filter(|cents| {
+        number.kind == NumberKind::Cardinal
+            && cents.kind == NumberKind::Cardinal
+            && (1..100).contains(&cents.numerator)
+    }) {
+        let hundredth = next_word(text, cents.span.end)
+            .filter(|(word, _)| hundredth_symbol(word).is_some())
+            .map(|(_, end)| end);
+        // `et` only joins the hundredths when they are named, as in `douze
+        // dollars et cinquante cents`.
+        let joiner = text[end..cents.span.start].trim();
+        if joiner.is_empty() || hundredth.is_some() && eq_ignore_case(joiner, "et") {
+            digits.push_str(&alloc::format!(",{:02}", cents.numerator));
+            end = hundredth.unwrap_or(cents.span.end);
+        }
+    }
+    let mut replacement = String::new();
+    push_amount(&mut replacement, &digits, symbol, options);
+    Some((number.span.start..end, replacement))
+}
+
+// Rewrite a number which is not an amount, or return `None` to leave it in
+// words.
+fn rewrite(
+    text: &str,
+    number: &ExtractedNumber,
+    options: &InverseOptions,
+) -> Option<(Range<usize>, String)> {
+    let following = next_word(text, number.span.end);
+    let is_month = |word: &str| MONTHS.iter().any(|m| eq_ignore_case(word, m));
+    let before_month = following.is_some_and(|(word, _)| is_month(word));
+    let after_month = text[..number.span.start]
+        .trim_end()
+        .rsplit(|c: char| !c.is_alphabetic())
+        .next()
+        .is_some_and(is_month);
+    let percent_end = following
+        .filter(|(word, _)| eq_ignore_case(word, "pour"))
+        .and_then(|(_, end)| next_word(text, end))
+        .filter(|(word, _)| eq_ignore_case(word, "cent"))
+        .map(|(_, end)| end);
+    let is_day = before_month && (1..=31).contains(&number.numerator);
+    let is_year = after_month
+        && number.kind == NumberKind::Cardinal
+        && (1..10_000).contains(&number.numerator);
+    let mut digits = String::new();
+    match number.kind {
+        NumberKind::Fraction => return None,
+        NumberKind::Decimal => push_number(&mut digits, number, &digits_options(options)),
+        NumberKind::Cardinal | NumberKind::Ordinal => {
+            if !is_day
+                && !is_year
+                && percent_end.is_none()
+                && number.numerator.unsigned_abs() < options.threshold
+            {
+                return None;
+            }
+            let digits_options = DigitsOptions {
+                min_grouped_digits: if is_year { usize::MAX } else { 4 },
+                ..digits_options(options)
+            };
+            push_number(&mut digits, number, &digits_options);
+            if number.kind == NumberKind::Ordinal {
+                let feminine = number.options.is_some_and(|o| o.feminine);
+                digits.push_str(match number.numerator {
+                    1 if feminine => "re",
+                    1 => "er",
+                    _ => "e",
+                });
+                if text[number.span.clone()].ends_with('s') {
+                    digits.push('s');
+                }
+            }
+        }
+    }
+    match percent_end {
+        Some(end) => {
+            digits.push_str("\u{a0}%");
+            Some((number.span.start..end, digits))
+        }
+        None => Some((number.span.clone(), digits)),
+    }
+}
@@ -1,15 +1,112 @@
 //! This crate transforms a number into its French representation
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![allow(clippy::non_ascii_literal)]
-#![doc = include_str!("../README.md")]
+// The examples of the README use the standard library.
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
 
+use core::fmt::{self, Display, Write};
 use num_integer::Integer;
 use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
-use std::fmt::Display;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+mod address;
+#[cfg(feature = "alloc")]
+mod alignment;
+mod approximate;
+#[cfg(feature = "alloc")]
+mod canonicalize;
+#[cfg(feature = "alloc")]
+mod check;
+mod compact;
+mod decimal;
+mod digits;
+#[cfg(feature = "alloc")]
+mod explain;
+#[cfg(feature = "alloc")]
+mod inverse;
+mod names;
+#[cfg(feature = "alloc")]
+mod normalize;
+#[cfg(feature = "alloc")]
+mod ordinal;
+#[cfg(feature = "alloc")]
+mod orthography;
+mod parse;
+#[cfg(feature = "alloc")]
+mod phonetic;
+mod roman;
+#[cfg(feature = "alloc")]
+mod scan;
+#[cfg(feature = "alloc")]
+mod to_french;
+mod tokens;
+
+#[cfg(feature = "alloc")]
+pub use address::french_street_number;
+pub use address::{
+    parse_french_street_number, write_french_street_number, AddressSuffix, StreetNumber,
+};
+#[cfg(feature = "alloc")]
+pub use alignment::{align_tokens, french_alignment, WordAlignment};
+#[cfg(feature = "alloc")]
+pub use approximate::approximate_french_number;
+pub use approximate::{write_approximate_french_number, ApproximateOptions, EnvironPolicy};
+#[cfg(feature = "alloc")]
+pub use canonicalize::canonicalize_french_number;
+#[cfg(feature = "alloc")]
+pub use check::{check_french_number, Diagnostic, DiagnosticKind};
+#[cfg(feature = "alloc")]
+pub use compact::compact_french_number;
+pub use compact::{
+    write_compact_french_number, CompactOptions, CompactUnit, Rounding, DOLLAR, EURO,
+};
+#[cfg(feature = "alloc")]
+pub use decimal::french_decimal_number;
+pub use decimal::{parse_french_numeral, write_french_decimal_number, Decimal, NumeralError};
+#[cfg(feature = "alloc")]
+pub use digits::{french_decimal_digits, french_digits};
+pub use digits::{
+    write_french_decimal_digits, write_french_digits, DigitsOptions, EURO_DIGITS, FRENCH_DIGITS,
+    PERCENT_DIGITS, YEAR_DIGITS,
+};
+#[cfg(feature = "alloc")]
+pub use explain::{explain_french_number, Explanation, Rule};
+#[cfg(feature = "alloc")]
+pub use inverse::{inverse_normalize_french_text, CurrencyPlacement, InverseOptions};
+#[cfg(feature = "alloc")]
+pub use normalize::normalize_french_text;
+#[cfg(feature = "alloc")]
+pub use ordinal::{
+    french_latin_ordinal_adverb, french_ordinal, french_ordinal_adverb, french_ordinal_options,
+};
+#[cfg(feature = "alloc")]
+pub use orthography::{analyze_orthography, Convention, Occurrence, OrthographyReport};
+pub use parse::{parse_french_number, ParseError};
+#[cfg(feature = "alloc")]
+pub use phonetic::{french_number_ipa, french_number_pronunciation, NextPhoneme, Pronunciation};
+#[cfg(feature = "alloc")]
+pub use roman::{french_roman_number, roman_numeral};
+pub use roman::{parse_roman_numeral, write_roman_numeral, RomanContext};
+#[cfg(feature = "alloc")]
+pub use scan::{extract_french_numbers, ExtractedNumber, NumberKind};
+#[cfg(feature = "alloc")]
+pub use to_french::ToFrench;
+use tokens::Word;
+#[cfg(feature = "alloc")]
+pub use tokens::{french_tokens, render_tokens};
+pub use tokens::{write_tokens, Joiner, Token, TokenKind};
 
 /// Options for French number representation
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Options {
     /// Set to `true` to get a feminine declination (default `false`).
     /// This only affects numbers ending in 1.
@@ -20,6 +117,45 @@ pub struct Options {
     pub reformed: bool,
 }
 
+/// Range of numbers written with the vigesimal counting of Old and Middle
+/// French, which survives in `quatre-vingts` and in the name of the Hôpital
+/// des Quinze-Vingts.
+///
+/// The numbers of the range from 40 to 399 are written as a multiple of
+/// `vingt`, possibly followed by a number smaller than twenty: 120 becomes
+/// `six-vingts`, 300 `quinze-vingts` and 145 `sept-vingt-cinq`. The range
+/// applies to each group of three digits, 120 000 becoming
+/// `six-vingt-mille`, and the numbers from 80 to 99 keep their usual form.
+/// The default range goes from 120 to 399.
+///
+/// See [`french_number_vigesimal`] and [`write_french_number_vigesimal`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Vigesimal {
+    /// Smallest number of the range.
+    pub min: u16,
+    /// Largest number of the range, included.
+    pub max: u16,
+}
+
+impl Default for Vigesimal {
+    fn default() -> Self {
+        Self { min: 120, max: 399 }
+    }
+}
+
+impl Vigesimal {
+    // The multiple of twenty used to write `n` with the vigesimal counting,
+    // if it must be. The multiples of `quatre-vingts` are left aside, being
+    // written the same way with the usual counting.
+    fn score(self, n: usize) -> Option<usize> {
+        let score = n / 20;
+        ((usize::from(self.min)..=usize::from(self.max)).contains(&n)
+            && (2..20).contains(&score)
+            && score != 4)
+            .then_some(score)
+    }
+}
+
 /// Pre 1990 reform masculine variant.
 pub static PRE_REFORM_MASCULINE: Options = Options {
     feminine: false,
@@ -60,13 +196,13 @@ impl Options {
     }
 }
 
-fn literal_for(value: usize, options: &Options) -> Option<String> {
+fn literal_for(value: usize, options: &Options) -> Option<&'static str> {
     static SMALLS: [&str; 21] = [
         "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix",
         "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit",
         "dix-neuf", "vingt",
     ];
-    let literal = if value == 1 && options.feminine {
+    if value == 1 && options.feminine {
         Some("une")
     } else if value <= 20 {
         Some(SMALLS[value])
@@ -78,224 +214,450 @@ fn literal_for(value: usize, options: &Options) -> Option<String> {
         Some("cinquante")
     } else if value == 60 {
         Some("soixante")
-    } else if value == 71 {
-        Some(if options.reformed {
-            "soixante-et-onze"
-        } else {
-            "soixante et onze"
-        })
-    } else if value == 80 {
-        Some("quatre-vingts")
-    } else if value == 81 {
-        Some(if options.feminine {
-            "quatre-vingt-une"
-        } else {
-            "quatre-vingt-un"
-        })
-    } else if value == 100 {
-        Some("cent")
-    } else if value == 1000 {
-        Some("mille")
     } else {
         None
-    };
-    literal.map(String::from)
-}
-
-fn add_unit_for(str: &mut String, prefix_count: usize, log1000: usize) -> bool {
-    static PREFIXES: [&str; 16] = [
-        "m",
-        "b",
-        "tr",
-        "quadr",
-        "quint",
-        "sext",
-        "sept",
-        "oct",
-        "non",
-        "déc",
-        "unodéc",
-        "duodéc",
-        "trédéc",
-        "quattuordéc",
-        "quindéc",
-        "sexdéc",
-    ];
-    PREFIXES.get(log1000 / 2).is_some_and(|prefix| {
-        str.push_str(prefix);
-        if log1000 % 2 == 0 {
-            str.push_str("illion");
-        } else {
-            str.push_str("illiard");
-        }
-        if prefix_count > 1 {
-            str.push('s');
-        }
-        true
-    })
+    }
 }
 
-fn unpluralize(str: &mut String) {
-    if str.ends_with("ts") {
-        str.truncate(str.len() - 1);
+/// Return the French multiplicative adjective for the given factor, such as
+/// `double` or `centuple`.
+///
+/// Only the factors from one to ten and a hundred have an established form,
+/// `None` is returned otherwise.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::french_multiplicative;
+///
+/// assert_eq!(french_multiplicative(&3), Some("triple"));
+/// assert_eq!(french_multiplicative(&100), Some("centuple"));
+/// assert_eq!(french_multiplicative(&11), None);
+/// ```
+pub fn french_multiplicative<N: ToPrimitive>(n: &N) -> Option<&'static str> {
+    static MULTIPLICATIVES: [&str; 10] = [
+        "simple",
+        "double",
+        "triple",
+        "quadruple",
+        "quintuple",
+        "sextuple",
+        "septuple",
+        "octuple",
+        "nonuple",
+        "décuple",
+    ];
+    match n.to_usize()? {
+        100 => Some("centuple"),
+        value => MULTIPLICATIVES.get(value.checked_sub(1)?).copied(),
     }
 }
 
-fn append_remainder(
-    mut str: String,
-    n: usize,
-    prefix_under_100: bool,
-    options: &Options,
-) -> String {
-    if n > 0 {
-        unpluralize(&mut str);
-        if n == 1 {
-            if prefix_under_100 && options.reformed {
-                str.push_str("-et-un");
-            } else if prefix_under_100 {
-                str.push_str(" et un");
-            } else if options.reformed {
-                str.push_str("-un");
-            } else {
-                str.push_str(" un");
-            }
-            if options.feminine {
-                str.push('e');
-            }
-        } else {
-            str.push(if options.reformed || (prefix_under_100 && n < 100) {
-                '-'
-            } else {
-                ' '
-            });
-            str.push_str(&to_french_repr(&n, options, false));
-        }
+/// Return the French collective noun, with its article, designating exactly
+/// the given number of items, such as `une douzaine` or `une grosse`.
+///
+/// Only `une paire` (2), `une demi-douzaine` (6), `une douzaine` (12) and
+/// `une grosse` (144) designate an exact quantity, `None` is returned
+/// otherwise. See [`approximate_french_number`] for approximate quantities
+/// such as `une vingtaine`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::french_collective;
+///
+/// assert_eq!(french_collective(&2), Some("une paire"));
+/// assert_eq!(french_collective(&144), Some("une grosse"));
+/// assert_eq!(french_collective(&20), None);
+/// ```
+pub fn french_collective<N: ToPrimitive>(n: &N) -> Option<&'static str> {
+    match n.to_usize()? {
+        2 => Some("une paire"),
+        6 => Some("une demi-douzaine"),
+        12 => Some("une douzaine"),
+        144 => Some("une grosse"),
+        _ => None,
     }
-    str
 }
 
-fn to_french_repr<N: Integer + FromPrimitive + ToPrimitive + Display>(
-    n: &N,
-    options: &Options,
-    negative: bool,
-) -> String {
-    n.to_usize()
-        .and_then(|n| {
-            literal_for(n, options).or_else(|| match n {
-                n if n < 60 => Some(smaller_than_60(n, options)),
-                n if n < 80 => Some(add_to_base(60, n, options)),
-                n if n < 100 => Some(add_to_base(80, n, options)),
-                n if n < 1000 => Some(smaller_than_1000(n, options)),
-                n if n < 2000 => Some(smaller_than_2000(n, options)),
-                n if n < 1_000_000 => Some(smaller_than_1000000(n, options)),
-                _ => None,
-            })
-        })
-        .map_or_else(
-            || over_1000000(n, options, negative),
-            |s| add_minus(s, negative),
-        )
-}
+static SCALE_PREFIXES: [&str; 16] = [
+    "m",
+    "b",
+    "tr",
+    "quadr",
+    "quint",
+    "sext",
+    "sept",
+    "oct",
+    "non",
+    "déc",
+    "unodéc",
+    "duodéc",
+    "trédéc",
+    "quattuordéc",
+    "quindéc",
+    "sexdéc",
+];
 
-fn smaller_than_60(n: usize, options: &Options) -> String {
-    let unit = n % 10;
-    append_remainder(
-        to_french_repr(&(n - unit), &Options::default(), false),
-        unit,
-        true,
-        options,
-    )
+fn add_unit_for<W: Write>(w: &mut W, prefix_count: usize, log1000: usize) -> fmt::Result {
+    w.write_str(SCALE_PREFIXES[log1000 / 2])?;
+    if log1000 % 2 == 0 {
+        w.write_str("illion")?;
+    } else {
+        w.write_str("illiard")?;
+    }
+    if prefix_count > 1 {
+        w.write_char('s')?;
+    }
+    Ok(())
 }
 
-fn add_to_base(b: usize, n: usize, options: &Options) -> String {
-    append_remainder(literal_for(b, options).unwrap(), n - b, true, options)
+fn digits_count(n: usize) -> usize {
+    n.checked_ilog10().unwrap_or(0) as usize + 1
 }
 
-fn smaller_than_1000(n: usize, options: &Options) -> String {
-    let (hundredths, rest) = n.div_rem(&100);
-    let result = if hundredths > 1 {
-        let mut prefix = literal_for(hundredths, options).unwrap();
-        push_space_or_dash(&mut prefix, options);
-        prefix.push_str("cents");
-        prefix
-    } else {
-        String::from("cent")
-    };
-    append_remainder(result, rest, false, options)
+// Emit the tokens of a number whose decimal representation has `len` digits.
+// Positions are expressed as powers of ten and converted into digit ranges.
+struct Emitter<F> {
+    emit: F,
+    len: usize,
+    vigesimal: Option<Vigesimal>,
 }
 
-fn smaller_than_2000(n: usize, options: &Options) -> String {
-    append_remainder(String::from("mille"), n - 1000, false, options)
-}
+impl<F: FnMut(Token) -> fmt::Result> Emitter<F> {
+    fn token(
+        &mut self,
+        joiner: Joiner,
+        kind: TokenKind,
+        word: Word,
+        high: usize,
+        low: usize,
+    ) -> fmt::Result {
+        (self.emit)(Token {
+            kind,
+            joiner,
+            digits: self.len - 1 - high..self.len - low,
+            word,
+        })
+    }
+
+    fn literal(
+        &mut self,
+        joiner: Joiner,
+        kind: TokenKind,
+        literal: &'static str,
+        high: usize,
+        low: usize,
+    ) -> fmt::Result {
+        self.token(joiner, kind, Word::Literal(literal), high, low)
+    }
 
-fn push_space_or_dash(str: &mut String, options: &Options) {
-    str.push(if options.reformed { '-' } else { ' ' });
+    fn sign(&mut self) -> fmt::Result {
+        (self.emit)(Token {
+            kind: TokenKind::Sign,
+            joiner: Joiner::None,
+            digits: 0..0,
+            word: Word::Literal("moins"),
+        })
+    }
+
+    // Emit "et" followed by `literal` standing for the unit at position `pos`. "et"
+    // itself stands for no digit.
+    fn et(
+        &mut self,
+        kind: TokenKind,
+        literal: &'static str,
+        pos: usize,
+        options: &Options,
+    ) -> fmt::Result {
+        let joiner = if options.reformed {
+            Joiner::Hyphen
+        } else {
+            Joiner::Space
+        };
+        self.literal(joiner, TokenKind::Et, "et", pos, pos + 1)?;
+        self.literal(joiner, kind, literal, pos, pos)
+    }
+
+    // Emit the non-zero remainder `n` following an already emitted prefix. `plural`
+    // tells whether a trailing "vingts" or "cents" may keep its final "s".
+    fn append_remainder(
+        &mut self,
+        n: usize,
+        pos: usize,
+        prefix_under_100: bool,
+        plural: bool,
+        options: &Options,
+    ) -> fmt::Result {
+        let un = literal_for(1, options).unwrap();
+        if n == 1 && prefix_under_100 {
+            self.et(TokenKind::Unit, un, pos, options)
+        } else if n == 1 {
+            self.literal(space_or_dash(options), TokenKind::Unit, un, pos, pos)
+        } else if (10..20).contains(&n) && prefix_under_100 {
+            // The tens digit already belongs to the prefix, as in "soixante-douze".
+            self.literal(
+                Joiner::Hyphen,
+                TokenKind::Teen,
+                literal_for(n, options).unwrap(),
+                pos,
+                pos,
+            )
+        } else {
+            let joiner = if options.reformed || (prefix_under_100 && n < 100) {
+                Joiner::Hyphen
+            } else {
+                Joiner::Space
+            };
+            self.smaller_than_1000(joiner, n, pos, plural, options)
+        }
+    }
+
+    fn smaller_than_1000(
+        &mut self,
+        joiner: Joiner,
+        n: usize,
+        pos: usize,
+        plural: bool,
+        options: &Options,
+    ) -> fmt::Result {
+        if let Some(score) = self.vigesimal.and_then(|v| v.score(n)) {
+            let kind = if score < 10 {
+                TokenKind::Unit
+            } else {
+                TokenKind::Teen
+            };
+            let high = if n < 100 { pos + 1 } else { pos + 2 };
+            let multiplier = literal_for(score, &options.masculinize()).unwrap();
+            self.literal(joiner, kind, multiplier, high, pos + 1)?;
+            let vingt = if n % 20 == 0 && plural {
+                "vingts"
+            } else {
+                "vingt"
+            };
+            self.literal(Joiner::Hyphen, TokenKind::Tens, vingt, pos + 1, pos + 1)?;
+            return match n % 20 {
+                0 => Ok(()),
+                1 => self.literal(
+                    Joiner::Hyphen,
+                    TokenKind::Unit,
+                    literal_for(1, options).unwrap(),
+                    pos,
+                    pos,
+                ),
+                rest => self.append_remainder(rest, pos, true, plural, options),
+            };
+        }
+        match n {
+            n if n < 10 => self.literal(
+                joiner,
+                TokenKind::Unit,
+                literal_for(n, options).unwrap(),
+                pos,
+                pos,
+            ),
+            n if n < 20 => self.literal(
+                joiner,
+                TokenKind::Teen,
+                literal_for(n, options).unwrap(),
+                pos + 1,
+                pos,
+            ),
+            n if n < 60 => {
+                let unit = n % 10;
+                self.literal(
+                    joiner,
+                    TokenKind::Tens,
+                    literal_for(n - unit, options).unwrap(),
+                    pos + 1,
+                    pos + 1,
+                )?;
+                if unit > 0 {
+                    self.append_remainder(unit, pos, true, plural, options)?;
+                }
+                Ok(())
+            }
+            n if n < 80 => {
+                self.literal(joiner, TokenKind::Tens, "soixante", pos + 1, pos + 1)?;
+                match n - 60 {
+                    0 => Ok(()),
+                    11 => self.et(TokenKind::Teen, "onze", pos, options),
+                    rest => self.append_remainder(rest, pos, true, plural, options),
+                }
+            }
+            n if n < 100 => {
+                let tens = if n == 80 && plural {
+                    "quatre-vingts"
+                } else {
+                    "quatre-vingt"
+                };
+                self.literal(joiner, TokenKind::Tens, tens, pos + 1, pos + 1)?;
+                match n - 80 {
+                    0 => Ok(()),
+                    1 => self.literal(
+                        Joiner::Hyphen,
+                        TokenKind::Unit,
+                        literal_for(1, options).unwrap(),
+                        pos,
+                        pos,
+                    ),
+                    rest => self.append_remainder(rest, pos, true, plural, options),
+                }
+            }
+            n => {
+                let (hundreds, rest) = n.div_rem(&100);
+                let mut joiner = joiner;
+                if hundreds > 1 {
+                    self.literal(
+                        joiner,
+                        TokenKind::Unit,
+                        literal_for(hundreds, options).unwrap(),
+                        pos + 2,
+                        pos + 2,
+                    )?;
+                    joiner = space_or_dash(options);
+                }
+                let cent = if rest == 0 && hundreds > 1 && plural {
+                    "cents"
+                } else {
+                    "cent"
+                };
+                self.literal(joiner, TokenKind::Hundred, cent, pos + 2, pos + 2)?;
+                if rest > 0 {
+                    // The vigesimal range applies to the whole group only.
+                    let vigesimal = self.vigesimal.take();
+                    let result = self.append_remainder(rest, pos, false, plural, options);
+                    self.vigesimal = vigesimal;
+                    result?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn smaller_than_1000000(&mut self, joiner: Joiner, n: usize, options: &Options) -> fmt::Result {
+        if n < 1000 {
+            return self.smaller_than_1000(joiner, n, 0, true, options);
+        }
+        let (thousands, rest) = n.div_rem(&1000);
+        let mut mille_joiner = joiner;
+        if thousands > 1 {
+            self.smaller_than_1000(joiner, thousands, 3, false, &options.masculinize())?;
+            mille_joiner = space_or_dash(options);
+        }
+        let high = (self.len - 1).min(5);
+        self.literal(mille_joiner, TokenKind::Thousand, "mille", high, 3)?;
+        if rest > 0 {
+            self.append_remainder(rest, 0, false, true, options)?;
+        }
+        Ok(())
+    }
 }
 
-fn smaller_than_1000000(n: usize, options: &Options) -> String {
-    let (thousands, rest) = n.div_rem(&1000);
-    let prefix = if thousands > 1 {
-        let mut thousands = to_french_repr(&thousands, &options.masculinize(), false);
-        unpluralize(&mut thousands);
-        push_space_or_dash(&mut thousands, options);
-        thousands.push_str("mille");
-        thousands
+fn space_or_dash(options: &Options) -> Joiner {
+    if options.reformed {
+        Joiner::Hyphen
     } else {
-        String::from("mille")
-    };
-    append_remainder(prefix, rest, false, options)
+        Joiner::Space
+    }
 }
 
-fn over_1000000<N: Integer + FromPrimitive + ToPrimitive + Display>(
+// Emit the tokens of the absolute value `n`, preceded by a sign if `negative` is set.
+// Return `false` without emitting anything if the number is too large to be spelled.
+fn emit_repr<N: Integer + FromPrimitive + ToPrimitive, F: FnMut(Token) -> fmt::Result>(
     n: &N,
     options: &Options,
+    vigesimal: Option<Vigesimal>,
     negative: bool,
-) -> String {
+    emit: F,
+) -> Result<bool, fmt::Error> {
+    if let Some(n) = n.to_usize().filter(|&n| n < 1_000_000) {
+        let mut emitter = Emitter {
+            emit,
+            len: digits_count(n),
+            vigesimal,
+        };
+        let mut joiner = Joiner::None;
+        if negative {
+            emitter.sign()?;
+            joiner = Joiner::Space;
+        }
+        emitter.smaller_than_1000000(joiner, n, options)?;
+        return Ok(true);
+    }
+    // Groups of three digits above the million, least significant first.
+    let mut groups = [0; 2 * SCALE_PREFIXES.len()];
     let thousand = N::from_u32(1000).unwrap();
     let (mut num, small) = n.div_rem(&N::from_u32(1_000_000).unwrap());
-    let mut base = if small == N::zero() {
-        None
-    } else {
-        Some(to_french_repr(&small, options, false))
-    };
-    let mut log1000 = 0;
+    let mut count = 0;
     while num != N::zero() {
-        let (rest, prefix) = num.div_rem(&thousand);
-        let prefix = prefix.to_usize().unwrap();
-        if prefix > 0 {
-            let mut str = to_french_repr(&prefix, &options.masculinize(), false);
-            push_space_or_dash(&mut str, options);
-            if !add_unit_for(&mut str, prefix, log1000) {
-                return add_minus_digits(n, negative);
-            }
-            if let Some(base) = base {
-                push_space_or_dash(&mut str, options);
-                str.push_str(&base);
-            }
-            base = Some(str);
+        if count == groups.len() {
+            return Ok(false);
         }
-        log1000 += 1;
+        let (rest, group) = num.div_rem(&thousand);
+        groups[count] = group.to_usize().unwrap();
+        count += 1;
         num = rest;
     }
-    base.map_or_else(|| add_minus_digits(n, negative), |s| add_minus(s, negative))
-}
-
-fn add_minus(s: String, negative: bool) -> String {
+    let mut emitter = Emitter {
+        emit,
+        len: 3 + 3 * count + digits_count(groups[count - 1]),
+        vigesimal,
+    };
+    let mut joiner = Joiner::None;
     if negative {
-        format!("moins {s}")
-    } else {
-        s
+        emitter.sign()?;
+        joiner = Joiner::Space;
+    }
+    for (log1000, &group) in groups[..count].iter().enumerate().rev() {
+        if group > 0 {
+            let pos = 6 + 3 * log1000;
+            emitter.smaller_than_1000(joiner, group, pos, true, &options.masculinize())?;
+            let high = (emitter.len - 1).min(pos + 2);
+            emitter.token(
+                space_or_dash(options),
+                TokenKind::Scale,
+                Word::Scale {
+                    log1000,
+                    plural: group > 1,
+                },
+                high,
+                pos,
+            )?;
+            joiner = space_or_dash(options);
+        }
+    }
+    let small = small.to_usize().unwrap();
+    if small > 0 {
+        emitter.smaller_than_1000000(joiner, small, options)?;
     }
+    Ok(true)
 }
 
-fn add_minus_digits<N>(n: N, negative: bool) -> String
-where
-    N: Display,
-{
-    if negative {
-        format!("-{n}")
+// Emit the tokens of any number, handling its sign. Return `false` without emitting
+// anything if the number is too large to be spelled.
+fn emit_french_number<
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+    F: FnMut(Token) -> fmt::Result,
+>(
+    n: &N,
+    options: &Options,
+    vigesimal: Option<Vigesimal>,
+    emit: F,
+) -> Result<bool, fmt::Error> {
+    if *n < N::zero() {
+        // Take the absolute value of n without consuming it. Since n is negative, we know that
+        // we can build the -1 constant. However, the positive value may not be properly
+        // representable with this type, in which case we widen it to an unsigned magnitude.
+        match N::from_i8(-1).and_then(|m1| m1.checked_mul(n)) {
+            Some(n) => emit_repr(&n, options, vigesimal, true, emit),
+            None => match n.to_i128() {
+                Some(n) => emit_repr(&n.unsigned_abs(), options, vigesimal, true, emit),
+                None => Ok(false),
+            },
+        }
     } else {
-        n.to_string()
+        emit_repr(n, options, vigesimal, false, emit)
     }
 }
 
@@ -304,9 +666,9 @@ where
 /// If the number is too large (greater than 10^103), then its numerical
 /// representation is returned with a leading minus sign if needed.
 ///
-/// Also, the smallest number of a bounded signed numerical type will be
-/// returned as a numerical representation because the opposite value
-/// cannot be computed. For example, `-128u8` will be shown as `-128`.
+/// The smallest number of a bounded signed numerical type is spelled
+/// as well, even though its opposite cannot be represented in the same
+/// type. For example, `i8::MIN` will be shown as `moins cent-vingt-huit`.
 ///
 /// By default, the masculine declination is used, as well as the preferred
 /// orthographic form introduced in the 1990 reform (use hyphens everywhere).
@@ -323,6 +685,7 @@ where
 /// assert_eq!(french_number(&-200000001), "moins deux-cents-millions-un");
 /// assert_eq!(french_number(&-204000001), "moins deux-cent-quatre-millions-un");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn french_number<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
     n: &N,
 ) -> String {
@@ -335,9 +698,9 @@ pub fn french_number<N: Integer + FromPrimitive + ToPrimitive + Display + Checke
 /// If the number is too large (greater than 10^103), then its numerical
 /// representation is returned with a leading minus sign if needed.
 ///
-/// Also, the smallest number of a bounded signed numerical type will be
-/// returned as a numerical representation because the opposite value
-/// cannot be computed. For example, `-128u8` will be shown as `-128`.
+/// The smallest number of a bounded signed numerical type is spelled
+/// as well, even though its opposite cannot be represented in the same
+/// type. For example, `i8::MIN` will be shown as `moins cent-vingt-huit`.
 ///
 /// # Example
 ///
@@ -353,58 +716,394 @@ pub fn french_number<N: Integer + FromPrimitive + ToPrimitive + Display + Checke
 /// assert_eq!(french_number_options(&37251061, &PRE_REFORM_MASCULINE),
 ///            "trente-sept millions deux cent cinquante et un mille soixante et un")
 /// ```
+#[cfg(feature = "alloc")]
 pub fn french_number_options<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
     n: &N,
     options: &Options,
 ) -> String {
-    if *n < N::zero() {
-        // Take the absolute value of n without consuming it. Since n is negative, we know that
-        // we can build the -1 constant. However, the positive value may not be properly
-        // representable with this type.
-        N::from_i8(-1)
-            .and_then(|m1| m1.checked_mul(n))
-            .map_or_else(|| n.to_string(), |n| to_french_repr(&n, options, true))
+    let mut s = String::new();
+    write_french_number(&mut s, n, options).expect("writing into a String cannot fail");
+    s
+}
+
+/// Write the French language representation of the given number with
+/// the given formatting options into a [`fmt::Write`] sink.
+///
+/// The words are streamed directly into the sink, without building any
+/// intermediate string. The output is identical to the one returned by
+/// [`french_number_options`].
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::from("total : ");
+/// write_french_number(&mut s, &1_080, &PRE_REFORM_MASCULINE).unwrap();
+/// assert_eq!(s, "total : mille quatre-vingts");
+/// ```
+pub fn write_french_number<
+    W: Write,
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+>(
+    w: &mut W,
+    n: &N,
+    options: &Options,
+) -> fmt::Result {
+    write_number(w, n, options, None)
+}
+
+fn write_number<W: Write, N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    w: &mut W,
+    n: &N,
+    options: &Options,
+    vigesimal: Option<Vigesimal>,
+) -> fmt::Result {
+    let spelled = emit_french_number(n, options, vigesimal, |token| {
+        write!(w, "{}{token}", token.joiner)
+    })?;
+    if spelled {
+        Ok(())
     } else {
-        to_french_repr(n, options, false)
+        write!(w, "{n}")
     }
 }
 
-#[cfg(test)]
+/// Write the French language representation of the given number with
+/// the given formatting options into a [`fmt::Write`] sink, using the
+/// vigesimal counting of Old and Middle French in the given range.
+///
+/// See [`Vigesimal`] for details.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut s = String::new();
+/// write_french_number_vigesimal(&mut s, &300, &POST_REFORM_MASCULINE, &Vigesimal::default())
+///     .unwrap();
+/// assert_eq!(s, "quinze-vingts");
+/// ```
+pub fn write_french_number_vigesimal<
+    W: Write,
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+>(
+    w: &mut W,
+    n: &N,
+    options: &Options,
+    vigesimal: &Vigesimal,
+) -> fmt::Result {
+    write_number(w, n, options, Some(*vigesimal))
+}
+
+/// Compute the French language representation of the given number with
+/// the given formatting options, using the vigesimal counting of Old and
+/// Middle French in the given range.
+///
+/// See [`Vigesimal`] for details.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let vigesimal = Vigesimal::default();
+/// assert_eq!(french_number_vigesimal(&120, &POST_REFORM_MASCULINE, &vigesimal), "six-vingts");
+/// assert_eq!(french_number_vigesimal(&145, &PRE_REFORM_MASCULINE, &vigesimal),
+///            "sept-vingt-cinq");
+/// assert_eq!(french_number_vigesimal(&400, &POST_REFORM_MASCULINE, &vigesimal), "quatre-cents");
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn french_number_vigesimal<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+    vigesimal: &Vigesimal,
+) -> String {
+    let mut s = String::new();
+    write_french_number_vigesimal(&mut s, n, options, vigesimal)
+        .expect("writing into a String cannot fail");
+    s
+}
+
+/// Write the French language representation of the given number with
+/// the given formatting options into an [`io::Write`] sink.
+///
+/// This is the [`io::Write`] counterpart of [`write_french_number`]. The
+/// output is UTF-8 encoded.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut out = Vec::new();
+/// write_french_number_io(&mut out, &21, &POST_REFORM_FEMININE).unwrap();
+/// assert_eq!(out, "vingt-et-une".as_bytes());
+/// ```
+#[cfg(feature = "std")]
+pub fn write_french_number_io<
+    W: io::Write,
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+>(
+    w: &mut W,
+    n: &N,
+    options: &Options,
+) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: None,
+    };
+    write_french_number(&mut adapter, n, options).map_err(|_| {
+        adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatter error"))
+    })
+}
+
+// Forward a fmt::Write stream into an io::Write sink, remembering the
+// underlying I/O error since fmt::Error cannot carry it.
+#[cfg(feature = "std")]
+struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write> Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// Write the French language representation of the given number with
+/// the given formatting options into a caller-provided buffer, and return
+/// the written part of the buffer.
+///
+/// This does not require any allocation and is available without the
+/// `alloc` feature.
+///
+/// # Errors
+///
+/// An error is returned if the buffer is too small to hold the result.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let mut buffer = [0; 32];
+/// assert_eq!(write_french_number_to_slice(&mut buffer, &-81, &POST_REFORM_FEMININE),
+///            Ok("moins quatre-vingt-une"));
+/// assert!(write_french_number_to_slice(&mut buffer[..4], &12, &POST_REFORM_MASCULINE).is_err());
+/// ```
+pub fn write_french_number_to_slice<
+    'a,
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+>(
+    buffer: &'a mut [u8],
+    n: &N,
+    options: &Options,
+) -> Result<&'a str, fmt::Error> {
+    let mut writer = SliceWriter { buffer, len: 0 };
+    write_french_number(&mut writer, n, options)?;
+    let SliceWriter { buffer, len } = writer;
+    // Only complete UTF-8 strings have been copied into the buffer.
+    Ok(core::str::from_utf8(&buffer[..len]).unwrap())
+}
+
+// Copy a fmt::Write stream into a byte buffer, failing when it is full.
+struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buffer
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Wrapper displaying a number using its French language representation.
+///
+/// The masculine declination is used. Format flags select the variant:
+///
+/// - `{:#}` uses the writing predating the 1990 orthographic reform;
+/// - `{:+}` prefixes strictly positive numbers with `plus`, as negative
+///   numbers are always prefixed with `moins`.
+///
+/// Width, fill, alignment and precision are honoured as they are for
+/// strings, without building any intermediate string.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::French;
+///
+/// assert_eq!(format!("{}", French(&1_080)), "mille-quatre-vingts");
+/// assert_eq!(format!("{:#}", French(&1_080)), "mille quatre-vingts");
+/// assert_eq!(format!("{:+}", French(&3)), "plus trois");
+/// assert_eq!(format!("{:+}", French(&-3)), "moins trois");
+/// assert_eq!(format!("[{:^9}]", French(&21)), "[vingt-et-un]");
+/// assert_eq!(format!("[{:*>7}]", French(&5)), "[***cinq]");
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct French<'a, N>(pub &'a N);
+
+impl<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul> French<'_, N> {
+    fn write_to<W: Write>(&self, w: &mut W, options: &Options, sign_plus: bool) -> fmt::Result {
+        if sign_plus && *self.0 > N::zero() {
+            w.write_str("plus ")?;
+        }
+        write_french_number(w, self.0, options)
+    }
+}
+
+impl<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul> Display for French<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = if f.alternate() {
+            &PRE_REFORM_MASCULINE
+        } else {
+            &POST_REFORM_MASCULINE
+        };
+        let sign_plus = f.sign_plus();
+        if f.width().is_none() && f.precision().is_none() {
+            return self.write_to(f, options, sign_plus);
+        }
+        let mut counter = CharCounter(0);
+        self.write_to(&mut counter, options, sign_plus)?;
+        let len = f.precision().map_or(counter.0, |p| p.min(counter.0));
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        let mut truncate = Truncate {
+            inner: &mut *f,
+            remaining: len,
+        };
+        self.write_to(&mut truncate, options, sign_plus)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+// Count the characters written, to compute the padding.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+// Forward at most `remaining` characters, to honour the precision.
+struct Truncate<'a, W> {
+    inner: &'a mut W,
+    remaining: usize,
+}
+
+impl<W: Write> Write for Truncate<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .char_indices()
+            .nth(self.remaining)
+            .map_or(s.len(), |(i, _)| i);
+        self.remaining -= s[..end].chars().count();
+        self.inner.write_str(&s[..end])
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
 
-    use crate::{add_unit_for, literal_for, to_french_repr, unpluralize};
+    use crate::{add_unit_for, emit_repr, literal_for, Emitter, Joiner, Options};
+    use alloc::string::{String, ToString};
+
+    fn to_french_repr(n: &usize, options: &Options, negative: bool) -> String {
+        let mut s = String::new();
+        emit_repr(n, options, None, negative, |token| {
+            s.push_str(token.joiner.as_str());
+            s.push_str(&token.to_string());
+            Ok(())
+        })
+        .unwrap();
+        s
+    }
+
+    fn smaller_than_1000(n: usize, plural: bool) -> String {
+        let mut s = String::new();
+        let mut emitter = Emitter {
+            emit: |token: crate::Token| {
+                s.push_str(token.joiner.as_str());
+                s.push_str(&token.to_string());
+                Ok(())
+            },
+            len: 3,
+            vigesimal: None,
+        };
+        emitter
+            .smaller_than_1000(Joiner::None, n, 0, plural, &Default::default())
+            .unwrap();
+        s
+    }
 
     #[test]
     fn test_literal_for() {
-        assert_eq!(
-            literal_for(30, &Default::default()),
-            Some(String::from("trente"))
-        );
+        assert_eq!(literal_for(30, &Default::default()), Some("trente"));
         assert_eq!(literal_for(31, &Default::default()), None);
     }
 
     #[test]
     fn test_add_unit_for() {
         let mut str = String::new();
-        assert!(add_unit_for(&mut str, 1, 0));
+        add_unit_for(&mut str, 1, 0).unwrap();
         assert_eq!(str, "million");
         str.clear();
-        assert!(add_unit_for(&mut str, 2, 0));
+        add_unit_for(&mut str, 2, 0).unwrap();
         assert_eq!(str, "millions");
         str.clear();
-        assert!(add_unit_for(&mut str, 1, 3));
+        add_unit_for(&mut str, 1, 3).unwrap();
         assert_eq!(str, "billiard");
-        assert!(!add_unit_for(&mut str, 1, 97));
     }
 
     #[test]
-    fn test_unpluralize() {
-        let mut s = String::from("quatre-cents");
-        unpluralize(&mut s);
-        assert_eq!(s, "quatre-cent");
-        let mut s = String::from("cent");
-        unpluralize(&mut s);
-        assert_eq!(s, "cent");
+    fn test_plural() {
+        assert_eq!(smaller_than_1000(400, true), "quatre-cents");
+        assert_eq!(smaller_than_1000(400, false), "quatre-cent");
+        assert_eq!(smaller_than_1000(100, true), "cent");
+        assert_eq!(smaller_than_1000(80, true), "quatre-vingts");
+        assert_eq!(smaller_than_1000(80, false), "quatre-vingt");
     }
 
     #[test]
@@ -0,0 +1,32 @@
+// Names of months and currencies shared by the normalization of texts and
+// the compact representation of numbers.
+
+#[cfg(feature = "alloc")]
+pub(crate) static MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+// A currency, with its name in the singular, its symbol and the name of its
+// hundredth.
+pub(crate) type Currency = (&'static str, &'static str, &'static str);
+
+pub(crate) const EURO: Currency = ("euro", "€", "centime");
+pub(crate) const DOLLAR: Currency = ("dollar", "$", "cent");
+#[cfg(feature = "alloc")]
+pub(crate) const POUND: Currency = ("livre", "£", "penny");
+#[cfg(feature = "alloc")]
+pub(crate) const FRANC: Currency = ("franc", "CHF", "centime");
+
+#[cfg(feature = "alloc")]
+pub(crate) static CURRENCIES: [Currency; 4] = [EURO, DOLLAR, POUND, FRANC];
@@ -0,0 +1,463 @@
+use crate::names::{Currency, CURRENCIES, DOLLAR, EURO, MONTHS, POUND};
+use crate::parse::{eq_ignore_case, lexeme, Lexeme};
+use crate::scan::is_group_separator;
+use crate::{
+    extract_french_numbers, french_decimal_number, french_number_options, french_ordinal_options,
+    french_street_number, parse_french_numeral, AddressSuffix, Decimal, ExtractedNumber,
+    NumberKind, Options, StreetNumber,
+};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+// Common feminine nouns, in the singular, that may follow a number.
+static FEMININE_NOUNS: [&str; 32] = [
+    "année",
+    "chambre",
+    "chose",
+    "classe",
+    "école",
+    "entreprise",
+    "étape",
+    "étoile",
+    "famille",
+    "femme",
+    "fille",
+    "fois",
+    "heure",
+    "journée",
+    "langue",
+    "ligne",
+    "maison",
+    "minute",
+    "page",
+    "partie",
+    "personne",
+    "pièce",
+    "place",
+    "question",
+    "région",
+    "route",
+    "rue",
+    "saison",
+    "seconde",
+    "semaine",
+    "tonne",
+    "voiture",
+];
+
+// Currency codes, with the currency they stand for.
+static CODES: [(&str, Currency); 3] = [("EUR", EURO), ("USD", DOLLAR), ("GBP", POUND)];
+
+/// Rewrite the numbers written in digits in a French text into words, as
+/// expected by a speech synthesizer.
+///
+/// The reading of each number depends on its context:
+///
+/// - a day followed by a month is read as a date, `1 mars` becoming
+///   `premier mars`, and numeric dates such as `21/03/2026` are read in
+///   full;
+/// - an amount followed by a currency symbol or code (`€`, `EUR`, `$`,
+///   `USD`, `£`, `GBP`, `CHF`) is read with the currency name and its
+///   hundredths, `12,50 €` becoming `douze euros cinquante`;
+/// - a number followed by `%` is read as a percentage;
+/// - ordinals such as `21e` or `1re` are read as ordinals;
+/// - street numbers such as `12bis` are read with their suffix;
+/// - other numbers are read as cardinals or decimals, in the feminine when
+///   they precede a feminine noun known to this function, such as
+///   `personnes` or `heures`.
+///
+/// URLs, email addresses, codes such as `A320`, phone numbers such as
+/// `01 23 45 67 89`, identifiers starting with a zero and numbers too large
+/// to be read are left untouched. The
+/// `reformed` field of the options selects the orthography, while the
+/// `feminine` one is ignored since the gender is inferred from the text.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(
+///     normalize_french_text("Le 21 mars 2026, 1 500 personnes ont payé 12,50 €.",
+///                           &POST_REFORM_MASCULINE),
+///     "Le vingt-et-un mars deux-mille-vingt-six, mille-cinq-cents personnes ont payé \
+///      douze euros cinquante.");
+/// assert_eq!(
+///     normalize_french_text("Voir https://exemple.fr/21 pour 21 heures de vol.",
+///                           &PRE_REFORM_MASCULINE),
+///     "Voir https://exemple.fr/21 pour vingt et une heures de vol.");
+/// ```
+#[must_use]
+pub fn normalize_french_text(text: &str, options: &Options) -> String {
+    let mut untouched = url_spans(text);
+    untouched.extend(identifier_spans(text));
+    let mut replacements = numeric_dates(text, options);
+    replacements.extend(street_numbers(text, options));
+    for number in extract_french_numbers(text) {
+        if number.options.is_none()
+            && !untouched
+                .iter()
+                .any(|span| span.contains(&number.span.start))
+            && !replacements
+                .iter()
+                .any(|(span, _)| span.contains(&number.span.start))
+        {
+            if let Some(replacement) = read(text, &number, options) {
+                replacements.push(replacement);
+            }
+        }
+    }
+    replacements.sort_unstable_by_key(|(span, _)| span.start);
+    let mut result = String::with_capacity(text.len() * 2);
+    let mut pos = 0;
+    for (span, words) in replacements {
+        result.push_str(&text[pos..span.start]);
+        result.push_str(&words);
+        pos = span.end;
+    }
+    result.push_str(&text[pos..]);
+    result
+}
+
+// Find the URLs and email addresses of a text.
+fn url_spans(text: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for word in text.split_whitespace() {
+        let start = pos + text[pos..].find(word).unwrap_or(0);
+        pos = start + word.len();
+        if word.contains("://") || word.starts_with("www.") || word.contains('@') {
+            spans.push(start..pos);
+        }
+    }
+    spans
+}
+
+// Find the phone numbers, made of at least four groups of one or two digits
+// or starting with a `+`, and the identifiers starting with a zero, such as
+// `0612345678`.
+fn identifier_spans(text: &str) -> Vec<Range<usize>> {
+    let digits_len = |from: usize| {
+        text[from..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len() - from)
+    };
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find(|c: char| c.is_ascii_digit()) {
+        let start = pos + offset;
+        let mut end = start;
+        let mut groups = Vec::new();
+        loop {
+            let len = digits_len(end);
+            groups.push(len);
+            end += len;
+            match text[end..].chars().next() {
+                Some(c)
+                    if is_group_separator(c)
+                        && text[end + c.len_utf8()..].starts_with(|c: char| c.is_ascii_digit()) =>
+                {
+                    end += c.len_utf8();
+                }
+                _ => break,
+            }
+        }
+        let plus = text[..start].ends_with('+');
+        let phone = plus || groups.len() >= 4 && groups.iter().all(|&len| len <= 2);
+        let identifier = groups[0] >= 2 && text[start..].starts_with('0');
+        if phone || identifier {
+            spans.push(start - usize::from(plus)..end);
+        }
+        pos = end;
+    }
+    spans
+}
+
+// Return the word following the given position, after some whitespace.
+fn next_word(text: &str, pos: usize) -> Option<&str> {
+    let rest = &text[pos..];
+    let start = pos + rest.find(|c: char| !c.is_whitespace())?;
+    if start == pos {
+        return None;
+    }
+    let end = text[start..]
+        .find(|c: char| !c.is_alphanumeric())
+        .map_or(text.len(), |i| start + i);
+    (end > start).then(|| &text[start..end])
+}
+
+fn is_month(word: &str) -> bool {
+    MONTHS.iter().any(|m| eq_ignore_case(word, m))
+}
+
+fn is_feminine_noun(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    let singular = lower.strip_suffix('s').unwrap_or(&lower);
+    FEMININE_NOUNS
+        .iter()
+        .any(|&noun| noun == lower || noun == singular)
+}
+
+fn gender(options: &Options, feminine: bool) -> Options {
+    Options {
+        feminine,
+        reformed: options.reformed,
+    }
+}
+
+// A number written in digits, along with the scale noun following it, if
+// any, as in `2,5 millions`.
+struct Numeral<'a> {
+    value: Decimal,
+    scale: &'a str,
+}
+
+fn numeral(text: &str) -> Option<Numeral<'_>> {
+    let (number, scale) = text.split_at(text.find(char::is_alphabetic).unwrap_or(text.len()));
+    Some(Numeral {
+        value: parse_french_numeral(number).ok()?,
+        scale,
+    })
+}
+
+// Spell a number written in digits, along with its scale noun.
+fn spell_numeral(numeral: &Numeral<'_>, options: &Options) -> String {
+    let mut words = french_decimal_number(&numeral.value, options);
+    if !numeral.scale.is_empty() {
+        words.push(' ');
+        words.push_str(numeral.scale);
+    }
+    words
+}
+
+// Return the currency symbol or code following the given position, if any,
+// along with the end of the symbol.
+fn currency_after(text: &str, pos: usize) -> Option<(usize, Currency)> {
+    let start = pos + text[pos..].find(|c: char| !c.is_whitespace())?;
+    let symbols = CURRENCIES.map(|currency| (currency.1, currency));
+    symbols
+        .into_iter()
+        .chain(CODES)
+        .find_map(|(symbol, currency)| {
+            let end = start + symbol.len();
+            let rest = text[start..].strip_prefix(symbol)?;
+            (!rest.chars().next().is_some_and(char::is_alphanumeric)).then_some((end, currency))
+        })
+}
+
+fn plural(word: &str, count: i128) -> &str {
+    match (word, count.unsigned_abs() >= 2) {
+        (_, false) => word,
+        ("penny", true) => "pence",
+        ("euro", true) => "euros",
+        ("centime", true) => "centimes",
+        ("dollar", true) => "dollars",
+        ("cent", true) => "cents",
+        ("livre", true) => "livres",
+        ("franc", true) => "francs",
+        _ => word,
+    }
+}
+
+// Append the name of a currency unit to an amount, with a `de` after scale
+// nouns as in `deux millions d'euros`.
+fn push_unit(words: &mut String, unit: &str) {
+    let last = words.rsplit([' ', '-']).next().unwrap_or("");
+    if matches!(lexeme(last), Some(Lexeme::Scale(_))) {
+        words.push_str(if unit.starts_with(['a', 'e', 'i', 'o', 'u']) {
+            " d'"
+        } else {
+            " de "
+        });
+    } else {
+        words.push(' ');
+    }
+    words.push_str(unit);
+}
+
+// Read an amount of money, such as `douze euros cinquante`.
+fn spell_amount(numeral: &Numeral<'_>, currency: Currency, options: &Options) -> String {
+    let (unit, _, hundredth) = currency;
+    // Only the pound has a feminine name, `livre`.
+    let options = gender(options, currency == POUND);
+    let Decimal { mantissa, scale } = numeral.value;
+    let mut words = String::new();
+    if scale > 2 || !numeral.scale.is_empty() {
+        words.push_str(&spell_numeral(numeral, &options));
+        push_unit(&mut words, plural(unit, 2));
+        return words;
+    }
+    let divisor = 10i128.pow(scale);
+    let integer = mantissa / divisor;
+    let cents = (mantissa % divisor).abs() * 10i128.pow(2 - scale);
+    if integer == 0 && cents > 0 {
+        if mantissa < 0 {
+            words.push_str("moins ");
+        }
+        words.push_str(&french_number_options(&cents, &gender(&options, false)));
+        words.push(' ');
+        words.push_str(plural(hundredth, cents));
+        return words;
+    }
+    words.push_str(&french_number_options(&integer, &options));
+    push_unit(&mut words, plural(unit, integer));
+    if cents > 0 {
+        words.push(' ');
+        words.push_str(&french_number_options(&cents, &gender(&options, false)));
+    }
+    words
+}
+
+// Compute the replacement of a number written in digits.
+fn read(text: &str, number: &ExtractedNumber, options: &Options) -> Option<(Range<usize>, String)> {
+    let span = number.span.clone();
+    let digits = &text[span.clone()];
+    let following = next_word(text, span.end);
+    if number.kind == NumberKind::Ordinal {
+        let suffix = digits.trim_start_matches(|c: char| c.is_ascii_digit() || c.is_whitespace());
+        let words = match suffix {
+            "nd" | "nds" => String::from("second") + &suffix[2..],
+            "nde" | "ndes" => String::from("seconde") + &suffix[3..],
+            _ => {
+                let feminine = matches!(suffix, "re" | "res" | "ère" | "ères" | "ʳᵉ");
+                let mut words =
+                    french_ordinal_options(&number.numerator, &gender(options, feminine))?;
+                if suffix.ends_with('s') {
+                    words.push('s');
+                }
+                words
+            }
+        };
+        return Some((span, words));
+    }
+    let numeral = numeral(digits)?;
+    if let Some((end, currency)) = currency_after(text, span.end) {
+        return Some((span.start..end, spell_amount(&numeral, currency, options)));
+    }
+    if let Some(end) = text[span.end..]
+        .trim_start()
+        .strip_prefix('%')
+        .map(|rest| text.len() - rest.len())
+    {
+        return Some((
+            span.start..end,
+            spell_numeral(&numeral, &gender(options, false)) + " pour cent",
+        ));
+    }
+    if number.kind == NumberKind::Cardinal
+        && number.numerator == 1
+        && following.is_some_and(is_month)
+    {
+        return Some((span, String::from("premier")));
+    }
+    let feminine = numeral.scale.is_empty() && following.is_some_and(is_feminine_noun);
+    Some((span, spell_numeral(&numeral, &gender(options, feminine))))
+}
+
+fn days_in_month(month: u16, year: u16) -> u16 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Find the dates written as `21/03/2026` and compute their reading.
+fn numeric_dates(text: &str, options: &Options) -> Vec<(Range<usize>, String)> {
+    let mut dates = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find(|c: char| c.is_ascii_digit()) {
+        let start = pos + offset;
+        let part = |from: usize, max: usize| {
+            let len = text[from..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(text.len() - from);
+            (1..=max)
+                .contains(&len)
+                .then(|| (text[from..from + len].parse::<u16>().ok(), from + len))
+        };
+        let date = (|| {
+            let (day, end) = part(start, 2)?;
+            let (month, end) = text[end..]
+                .starts_with('/')
+                .then(|| part(end + 1, 2))
+                .flatten()?;
+            let (year, end) = text[end..]
+                .starts_with('/')
+                .then(|| part(end + 1, 4))
+                .flatten()?;
+            let (day, month, year) = (day?, month?, year?);
+            let after = text[end..].chars().next();
+            let before = text[..start].chars().next_back();
+            ((1..=12).contains(&month)
+                && (1..=days_in_month(month, year)).contains(&day)
+                && !after.is_some_and(|c| c.is_alphanumeric() || c == '/')
+                && !before.is_some_and(|c| c.is_alphanumeric() || c == '/'))
+            .then_some((day, month, year, end))
+        })();
+        match date {
+            Some((day, month, year, end)) => {
+                let mut words = if day == 1 {
+                    String::from("premier")
+                } else {
+                    french_number_options(&day, &gender(options, false))
+                };
+                words.push(' ');
+                words.push_str(MONTHS[usize::from(month) - 1]);
+                words.push(' ');
+                words.push_str(&french_number_options(&year, &gender(options, false)));
+                dates.push((start..end, words));
+                pos = end;
+            }
+            None => {
+                pos = start
+                    + text[start..]
+                        .find(|c: char| !c.is_ascii_digit())
+                        .unwrap_or(text.len() - start);
+            }
+        }
+    }
+    dates
+}
+
+// Find the street numbers written with their suffix stuck to the digits, as
+// in `12bis`, and compute their reading.
+fn street_numbers(text: &str, options: &Options) -> Vec<(Range<usize>, String)> {
+    let mut numbers = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find(|c: char| c.is_ascii_digit()) {
+        let start = pos + offset;
+        let digits_end = text[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(text.len(), |i| start + i);
+        let end = text[digits_end..]
+            .find(|c: char| !c.is_alphabetic())
+            .map_or(text.len(), |i| digits_end + i);
+        pos = end;
+        if text[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric())
+            || text[end..].starts_with(|c: char| c.is_ascii_digit())
+        {
+            continue;
+        }
+        let (Some(suffix), Ok(number)) = (
+            AddressSuffix::from_word(&text[digits_end..end]),
+            text[start..digits_end].parse(),
+        ) else {
+            continue;
+        };
+        let street_number = StreetNumber {
+            number,
+            suffix: Some(suffix),
+        };
+        numbers.push((
+            start..end,
+            french_street_number(&street_number, &gender(options, false)),
+        ));
+    }
+    numbers
+}
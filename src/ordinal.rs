@@ -0,0 +1,191 @@
+use crate::{write_french_number, Options};
+use alloc::string::String;
+use core::fmt::Display;
+use num_integer::Integer;
+use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
+
+/// Compute the French language ordinal representation of the given number.
+///
+/// Ordinals only exist for strictly positive numbers, `None` is returned
+/// otherwise, as well as for numbers too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::french_ordinal;
+///
+/// assert_eq!(french_ordinal(&1).as_deref(), Some("premier"));
+/// assert_eq!(french_ordinal(&21).as_deref(), Some("vingt-et-unième"));
+/// assert_eq!(french_ordinal(&80).as_deref(), Some("quatre-vingtième"));
+/// assert_eq!(french_ordinal(&0), None);
+/// ```
+pub fn french_ordinal<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+) -> Option<String> {
+    french_ordinal_options(n, &Options::default())
+}
+
+/// Compute the French language ordinal representation of the given number
+/// with the given formatting options.
+///
+/// The feminine declination only affects the first ordinal, `première`.
+/// Ordinals only exist for strictly positive numbers, `None` is returned
+/// otherwise, as well as for numbers too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(french_ordinal_options(&1, &POST_REFORM_FEMININE).as_deref(), Some("première"));
+/// assert_eq!(french_ordinal_options(&41, &POST_REFORM_FEMININE).as_deref(),
+///            Some("quarante-et-unième"));
+/// assert_eq!(french_ordinal_options(&2_000_000, &PRE_REFORM_MASCULINE).as_deref(),
+///            Some("deux millionième"));
+/// ```
+pub fn french_ordinal_options<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+) -> Option<String> {
+    if *n <= N::zero() {
+        return None;
+    }
+    let mut s = String::new();
+    write_french_number(&mut s, n, options).ok()?;
+    if s.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    make_ordinal(&mut s, options);
+    Some(s)
+}
+
+/// Compute the French language ordinal adverb of the given number, such as
+/// `premièrement` or `vingt-et-unièmement`.
+///
+/// The adverb is built from the feminine ordinal, and follows the spelling
+/// convention of the options. Adverbs only exist for strictly positive
+/// numbers, `None` is returned otherwise, as well as for numbers too large
+/// to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(french_ordinal_adverb(&1, &POST_REFORM_MASCULINE).as_deref(),
+///            Some("premièrement"));
+/// assert_eq!(french_ordinal_adverb(&21, &POST_REFORM_MASCULINE).as_deref(),
+///            Some("vingt-et-unièmement"));
+/// assert_eq!(french_ordinal_adverb(&21, &PRE_REFORM_MASCULINE).as_deref(),
+///            Some("vingt et unièmement"));
+/// assert_eq!(french_ordinal_adverb(&0, &POST_REFORM_MASCULINE), None);
+/// ```
+pub fn french_ordinal_adverb<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+) -> Option<String> {
+    let options = Options {
+        feminine: true,
+        ..*options
+    };
+    let mut s = french_ordinal_options(n, &options)?;
+    s.push_str("ment");
+    Some(s)
+}
+
+/// Return the Latin ordinal adverb used as a list marker in French, from
+/// `primo` to `decimo`.
+///
+/// `None` is returned for numbers outside of this range.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::french_latin_ordinal_adverb;
+///
+/// assert_eq!(french_latin_ordinal_adverb(&2), Some("secundo"));
+/// assert_eq!(french_latin_ordinal_adverb(&4), Some("quarto"));
+/// assert_eq!(french_latin_ordinal_adverb(&11), None);
+/// ```
+pub fn french_latin_ordinal_adverb<N: ToPrimitive>(n: &N) -> Option<&'static str> {
+    static LATIN: [&str; 10] = [
+        "primo", "secundo", "tertio", "quarto", "quinto", "sexto", "septimo", "octavo", "nono",
+        "decimo",
+    ];
+    LATIN.get(n.to_usize()?.checked_sub(1)?).copied()
+}
+
+// Turn the cardinal representation into an ordinal one by transforming its last word.
+fn make_ordinal(s: &mut String, options: &Options) {
+    let mut start = s.rfind(['-', ' ']).map_or(0, |i| i + 1);
+    let scale = s[start..].trim_end_matches('s');
+    if scale.ends_with("illion") || scale.ends_with("illiard") {
+        // `un million` gives `millionième`, and `vingts` and `cents` are no
+        // longer final in `quatre-vingt-millionième`.
+        if start == 3 && s.starts_with("un") {
+            s.drain(..start);
+            start = 0;
+        } else if s[..start - 1].ends_with("vingts") || s[..start - 1].ends_with("cents") {
+            s.remove(start - 2);
+            start -= 1;
+        }
+    }
+    match &s[start..] {
+        "un" | "une" if start == 0 => {
+            s.clear();
+            s.push_str(if options.feminine {
+                "première"
+            } else {
+                "premier"
+            });
+            return;
+        }
+        "une" => s.truncate(s.len() - 1),
+        "cinq" => s.push('u'),
+        "neuf" => {
+            s.truncate(s.len() - 1);
+            s.push('v');
+        }
+        "trois" => (),
+        word if word.ends_with(['e', 's']) => s.truncate(s.len() - 1),
+        _ => (),
+    }
+    s.push_str("ième");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::make_ordinal;
+    use alloc::string::String;
+
+    fn ordinal(s: &str) -> String {
+        let mut s = String::from(s);
+        make_ordinal(&mut s, &Default::default());
+        s
+    }
+
+    #[test]
+    fn test_make_ordinal() {
+        assert_eq!(ordinal("un"), "premier");
+        assert_eq!(ordinal("deux"), "deuxième");
+        assert_eq!(ordinal("trois"), "troisième");
+        assert_eq!(ordinal("quatre"), "quatrième");
+        assert_eq!(ordinal("cinq"), "cinquième");
+        assert_eq!(ordinal("neuf"), "neuvième");
+        assert_eq!(ordinal("onze"), "onzième");
+        assert_eq!(ordinal("trente-et-un"), "trente-et-unième");
+        assert_eq!(ordinal("trente et une"), "trente et unième");
+        assert_eq!(ordinal("quatre-vingts"), "quatre-vingtième");
+        assert_eq!(ordinal("deux-cents"), "deux-centième");
+        assert_eq!(ordinal("mille"), "millième");
+        assert_eq!(ordinal("trois-milliards"), "trois-milliardième");
+        assert_eq!(ordinal("un-million"), "millionième");
+        assert_eq!(ordinal("un milliard"), "milliardième");
+        assert_eq!(
+            ordinal("quatre-vingts-millions"),
+            "quatre-vingt-millionième"
+        );
+        assert_eq!(ordinal("deux cents millions"), "deux cent millionième");
+        assert_eq!(ordinal("vingt-et-un-millions"), "vingt-et-un-millionième");
+    }
+}
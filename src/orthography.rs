@@ -0,0 +1,167 @@
+use crate::parse::{text_words, Lexeme};
+use crate::scan::{find_spelled_numbers, word_lexeme};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Orthographic convention followed by a French number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Convention {
+    /// Before the 1990 reform: spaces around `cent`, `mille`, the scale
+    /// nouns and `et`.
+    Traditional,
+    /// Since the 1990 reform: hyphens everywhere.
+    Reformed,
+    /// Standard tens: `soixante-dix`, `quatre-vingts`, `quatre-vingt-dix`.
+    StandardTens,
+    /// Belgian and Swiss tens: `septante`, `huitante` or `octante`,
+    /// `nonante`.
+    RegionalTens,
+    /// `mille` for one thousand.
+    Mille,
+    /// `mil` for one thousand, as found in dates.
+    Mil,
+}
+
+impl Convention {
+    /// The convention competing with this one.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Traditional => Self::Reformed,
+            Self::Reformed => Self::Traditional,
+            Self::StandardTens => Self::RegionalTens,
+            Self::RegionalTens => Self::StandardTens,
+            Self::Mille => Self::Mil,
+            Self::Mil => Self::Mille,
+        }
+    }
+}
+
+/// A French number spelled in words found in a text, along with the
+/// conventions it follows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Occurrence {
+    /// The byte range of the number in the text.
+    pub span: Range<usize>,
+    /// The value of the number.
+    pub value: i128,
+    /// The conventions followed by the number, in the order of
+    /// [`Convention`]. A number such as `vingt-deux` follows no particular
+    /// convention, while a number using competing conventions, such as
+    /// `deux cent-trois`, lists both.
+    pub conventions: Vec<Convention>,
+}
+
+/// Conventions followed by the French numbers spelled in a text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrthographyReport {
+    /// The numbers found in the text.
+    pub occurrences: Vec<Occurrence>,
+    /// The conventions followed by a strict majority of the numbers among
+    /// competing conventions, in the order of [`Convention`].
+    pub majority: Vec<Convention>,
+    /// The indices in `occurrences` of the numbers following a convention
+    /// competing with the majority one.
+    pub inconsistent: Vec<usize>,
+}
+
+/// Find the French numbers spelled in words in a text, and report the
+/// orthographic conventions they follow.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let report = analyze_orthography(
+///     "Il a vendu deux cents vaches, trois cent une chèvres et deux-cent-vingt moutons.");
+/// assert_eq!(report.occurrences.len(), 3);
+/// assert_eq!(report.occurrences[2].conventions, [Convention::Reformed]);
+/// assert_eq!(report.majority, [Convention::Traditional]);
+/// assert_eq!(report.inconsistent, [2]);
+/// ```
+#[must_use]
+pub fn analyze_orthography(text: &str) -> OrthographyReport {
+    let occurrences = find_spelled_numbers(text)
+        .into_iter()
+        .map(|number| Occurrence {
+            conventions: conventions(&text[number.span.clone()]),
+            span: number.span,
+            value: number.value,
+        })
+        .collect::<Vec<_>>();
+    let count = |convention| {
+        occurrences
+            .iter()
+            .filter(|o| o.conventions.contains(&convention))
+            .count()
+    };
+    let majority = [
+        Convention::Traditional,
+        Convention::Reformed,
+        Convention::StandardTens,
+        Convention::RegionalTens,
+        Convention::Mille,
+        Convention::Mil,
+    ]
+    .into_iter()
+    .filter(|&c| count(c) > count(c.opposite()))
+    .collect::<Vec<_>>();
+    let inconsistent = occurrences
+        .iter()
+        .enumerate()
+        .filter(|(_, o)| {
+            majority
+                .iter()
+                .any(|c| o.conventions.contains(&c.opposite()))
+        })
+        .map(|(i, _)| i)
+        .collect();
+    OrthographyReport {
+        occurrences,
+        majority,
+        inconsistent,
+    }
+}
+
+pub(crate) fn conventions(number: &str) -> Vec<Convention> {
+    let mut conventions = Vec::new();
+    let mut previous: Option<(&str, Lexeme)> = None;
+    for word in text_words(number) {
+        let Some(lexeme) = word_lexeme(word.word) else {
+            continue;
+        };
+        let lower = word.word.to_lowercase();
+        match lexeme {
+            Lexeme::Number(70 | 80 | 90) => conventions.push(Convention::RegionalTens),
+            Lexeme::Number(10..=19) if previous.is_some_and(|(_, l)| l == Lexeme::Number(60)) => {
+                conventions.push(Convention::StandardTens);
+            }
+            Lexeme::Number(20) if previous.is_some_and(|(_, l)| l == Lexeme::Number(4)) => {
+                conventions.push(Convention::StandardTens);
+            }
+            Lexeme::Mille if lower == "mil" => conventions.push(Convention::Mil),
+            Lexeme::Mille => conventions.push(Convention::Mille),
+            _ => (),
+        }
+        if let Some((_, before)) = previous.filter(|&(_, l)| l != Lexeme::Moins) {
+            let large = |l| {
+                matches!(
+                    l,
+                    Lexeme::Cent | Lexeme::Mille | Lexeme::Scale(_) | Lexeme::Et
+                )
+            };
+            if large(before) || large(lexeme) {
+                conventions.push(if number[word.joiner].contains('-') {
+                    Convention::Reformed
+                } else {
+                    Convention::Traditional
+                });
+            }
+        }
+        previous = Some((word.word, lexeme));
+    }
+    conventions.sort_unstable();
+    conventions.dedup();
+    conventions
+}
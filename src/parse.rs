@@ -0,0 +1,369 @@
+use crate::SCALE_PREFIXES;
+use core::fmt::{self, Display};
+use core::ops::Range;
+
+/// Error returned when parsing the French representation of a number fails.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The text does not contain any number.
+    Empty,
+    /// The word at the given byte range is not part of the French numbers
+    /// vocabulary.
+    UnknownWord(Range<usize>),
+    /// The word at the given byte range cannot appear at this place.
+    UnexpectedWord(Range<usize>),
+    /// The number is too large to be represented.
+    Overflow,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "no number found"),
+            Self::UnknownWord(span) => write!(f, "unknown word at {span:?}"),
+            Self::UnexpectedWord(span) => write!(f, "unexpected word at {span:?}"),
+            Self::Overflow => write!(f, "number too large"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+// A word of a text, along with the separator preceding it.
+#[derive(Clone, Debug)]
+pub(crate) struct Atom<'a> {
+    pub(crate) word: &'a str,
+    pub(crate) span: Range<usize>,
+    #[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+    pub(crate) joiner: Range<usize>,
+}
+
+pub(crate) fn is_separator(c: char) -> bool {
+    c == '-' || c.is_whitespace()
+}
+
+// Split a text into words separated by hyphens and spaces.
+pub(crate) fn atoms(text: &str) -> impl Iterator<Item = Atom<'_>> + Clone {
+    let mut pos = 0;
+    core::iter::from_fn(move || {
+        let rest = &text[pos..];
+        let start = pos + rest.find(|c| !is_separator(c))?;
+        let end = text[start..]
+            .find(is_separator)
+            .map_or(text.len(), |i| start + i);
+        let atom = Atom {
+            word: &text[start..end],
+            span: start..end,
+            joiner: pos..start,
+        };
+        pos = end;
+        Some(atom)
+    })
+}
+
+// Split a running text into words made of letters, the joiner of each word being
+// whatever separates it from the previous one.
+#[cfg(feature = "alloc")]
+pub(crate) fn text_words(text: &str) -> impl Iterator<Item = Atom<'_>> + Clone {
+    let mut pos = 0;
+    core::iter::from_fn(move || {
+        let rest = &text[pos..];
+        let start = pos + rest.find(char::is_alphabetic)?;
+        let end = text[start..]
+            .find(|c: char| !c.is_alphabetic())
+            .map_or(text.len(), |i| start + i);
+        let atom = Atom {
+            word: &text[start..end],
+            span: start..end,
+            joiner: pos..start,
+        };
+        pos = end;
+        Some(atom)
+    })
+}
+
+pub(crate) fn eq_ignore_case(word: &str, lowercase: &str) -> bool {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .eq(lowercase.chars())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Lexeme {
+    Moins,
+    // Units, teens and tens, including the Belgian and Swiss forms.
+    Number(u8),
+    Et,
+    Cent,
+    Mille,
+    Scale(usize),
+}
+
+pub(crate) fn lexeme(word: &str) -> Option<Lexeme> {
+    static NUMBERS: [(&str, u8); 31] = [
+        ("zéro", 0),
+        ("un", 1),
+        ("une", 1),
+        ("deux", 2),
+        ("trois", 3),
+        ("quatre", 4),
+        ("cinq", 5),
+        ("six", 6),
+        ("sept", 7),
+        ("huit", 8),
+        ("neuf", 9),
+        ("dix", 10),
+        ("onze", 11),
+        ("douze", 12),
+        ("treize", 13),
+        ("quatorze", 14),
+        ("quinze", 15),
+        ("seize", 16),
+        ("vingt", 20),
+        ("vingts", 20),
+        ("trente", 30),
+        ("quarante", 40),
+        ("cinquante", 50),
+        ("soixante", 60),
+        ("septante", 70),
+        ("huitante", 80),
+        ("octante", 80),
+        ("nonante", 90),
+        ("cent", 100),
+        ("cents", 100),
+        ("et", 0),
+    ];
+    if eq_ignore_case(word, "moins") {
+        return Some(Lexeme::Moins);
+    }
+    if ["mille", "milles", "mil"]
+        .iter()
+        .any(|m| eq_ignore_case(word, m))
+    {
+        return Some(Lexeme::Mille);
+    }
+    if let Some(&(literal, value)) = NUMBERS.iter().find(|(w, _)| eq_ignore_case(word, w)) {
+        return Some(match literal {
+            "et" => Lexeme::Et,
+            "cent" | "cents" => Lexeme::Cent,
+            _ => Lexeme::Number(value),
+        });
+    }
+    scale_for(word).map(Lexeme::Scale)
+}
+
+// Find the power of thousand (above the million) designated by a scale noun.
+fn scale_for(word: &str) -> Option<usize> {
+    let lower = word.chars().flat_map(char::to_lowercase);
+    (0..2 * SCALE_PREFIXES.len()).find(|&log1000| {
+        let suffix = if log1000 % 2 == 0 {
+            "illion"
+        } else {
+            "illiard"
+        };
+        let singular = SCALE_PREFIXES[log1000 / 2].chars().chain(suffix.chars());
+        lower.clone().eq(singular.clone()) || lower.clone().eq(singular.chain(Some('s')))
+    })
+}
+
+// The last word seen, used to check that words come in an acceptable order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Last {
+    Start,
+    Moins,
+    Zero,
+    Unit(u8),
+    Dix,
+    Teen,
+    Tens(u8),
+    // `vingt` following a multiplier other than `quatre`, as in `six-vingts`.
+    Vingts,
+    Et,
+    Cent,
+    Mille,
+    Scale(usize),
+}
+
+// Accumulate the value of a French number, one word at a time.
+#[derive(Debug)]
+pub(crate) struct Accumulator {
+    negative: bool,
+    total: u128,
+    // Thousands accumulated since the last scale noun.
+    section: u128,
+    // Value accumulated since the last "mille" or scale noun, below one
+    // thousand except for hundreds counted from eleven to nineteen.
+    current: u16,
+    last: Last,
+    last_scale: Option<usize>,
+}
+
+impl Accumulator {
+    pub(crate) const fn new() -> Self {
+        Self {
+            negative: false,
+            total: 0,
+            section: 0,
+            current: 0,
+            last: Last::Start,
+            last_scale: None,
+        }
+    }
+
+    // Add a word, or return an error if it cannot appear at this place. The
+    // accumulator is left untouched in case of error.
+    pub(crate) fn push(&mut self, lexeme: Lexeme, span: Range<usize>) -> Result<(), ParseError> {
+        let low = self.current % 100;
+        let after_tens = matches!(self.last, Last::Tens(_) | Last::Et | Last::Vingts);
+        self.last = match lexeme {
+            Lexeme::Moins if self.last == Last::Start => {
+                self.negative = true;
+                Last::Moins
+            }
+            Lexeme::Number(0) if matches!(self.last, Last::Start | Last::Moins) => Last::Zero,
+            Lexeme::Number(20) if self.last == Last::Unit(4) && low == 4 => {
+                self.current += 76;
+                Last::Tens(80)
+            }
+            Lexeme::Number(20)
+                if matches!(self.last, Last::Unit(_) | Last::Dix | Last::Teen)
+                    && (2..20).contains(&self.current) =>
+            {
+                self.current *= 20;
+                Last::Vingts
+            }
+            Lexeme::Number(v @ 1..=9)
+                if low == 0 && !matches!(self.last, Last::Unit(_) | Last::Zero | Last::Dix)
+                    || after_tens
+                    || self.last == Last::Dix && v >= 7 =>
+            {
+                self.current += u16::from(v);
+                Last::Unit(v)
+            }
+            Lexeme::Number(v @ 10..=16)
+                if low == 0 && !matches!(self.last, Last::Unit(_) | Last::Zero)
+                    || after_tens && matches!(low, 60 | 80)
+                    || self.last == Last::Vingts =>
+            {
+                self.current += u16::from(v);
+                if v == 10 {
+                    Last::Dix
+                } else {
+                    Last::Teen
+                }
+            }
+            Lexeme::Number(v @ 20..)
+                if low == 0 && !matches!(self.last, Last::Unit(_) | Last::Zero | Last::Vingts) =>
+            {
+                self.current += u16::from(v);
+                Last::Tens(v)
+            }
+            Lexeme::Et if matches!(self.last, Last::Tens(_)) => Last::Et,
+            Lexeme::Cent if self.current < 10 && !matches!(self.last, Last::Zero | Last::Cent) => {
+                self.current = self.current.max(1) * 100;
+                Last::Cent
+            }
+            // Hundreds may be counted from eleven to nineteen at the start of
+            // a number, as in `douze cents` or `dix-neuf cent quatre-vingt-quatre`.
+            Lexeme::Cent
+                if (11..20).contains(&self.current)
+                    && matches!(self.last, Last::Teen | Last::Unit(_))
+                    && self.section == 0
+                    && self.last_scale.is_none() =>
+            {
+                self.current *= 100;
+                Last::Cent
+            }
+            Lexeme::Mille
+                if self.section == 0
+                    && !matches!(self.last, Last::Zero | Last::Mille | Last::Et) =>
+            {
+                self.section = u128::from(self.current.max(1))
+                    .checked_mul(1000)
+                    .ok_or(ParseError::Overflow)?;
+                self.current = 0;
+                Last::Mille
+            }
+            Lexeme::Scale(log1000)
+                if self.last_scale.is_none_or(|last| log1000 < last)
+                    && !matches!(
+                        self.last,
+                        Last::Zero | Last::Et | Last::Moins | Last::Scale(_)
+                    ) =>
+            {
+                let multiplier = self
+                    .section
+                    .checked_add(u128::from(self.current))
+                    .ok_or(ParseError::Overflow)?
+                    .max(1);
+                self.total = 1000u128
+                    .checked_pow(log1000 as u32 + 2)
+                    .and_then(|scale| multiplier.checked_mul(scale))
+                    .and_then(|value| self.total.checked_add(value))
+                    .ok_or(ParseError::Overflow)?;
+                self.section = 0;
+                self.current = 0;
+                self.last_scale = Some(log1000);
+                Last::Scale(log1000)
+            }
+            _ => return Err(ParseError::UnexpectedWord(span)),
+        };
+        Ok(())
+    }
+
+    // Check whether at least one number word has been seen, and the number may end here.
+    pub(crate) fn is_complete(&self) -> bool {
+        !matches!(self.last, Last::Start | Last::Moins | Last::Et)
+    }
+
+    // The value accumulated so far, or `None` if it does not fit.
+    pub(crate) fn value(&self) -> Option<i128> {
+        let magnitude = self
+            .total
+            .checked_add(self.section)?
+            .checked_add(u128::from(self.current))?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+/// Parse the French language representation of a number.
+///
+/// Words may be separated by hyphens or spaces, regardless of the 1990
+/// orthographic reform, and common mistakes such as a missing plural are
+/// tolerated. The Belgian and Swiss forms (`septante`, `huitante`,
+/// `octante` and `nonante`) are accepted as well.
+///
+/// # Errors
+///
+/// An error is returned if the text does not contain a valid number, or if
+/// this number does not fit into an `i128`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(parse_french_number("quatre-vingt-dix-sept"), Ok(97));
+/// assert_eq!(parse_french_number("moins deux cent mille un"), Ok(-200_001));
+/// assert_eq!(parse_french_number("Trois-Milliards"), Ok(3_000_000_000));
+/// assert_eq!(parse_french_number("nonante-sept"), Ok(97));
+/// assert_eq!(parse_french_number("deux trois"), Err(ParseError::UnexpectedWord(5..10)));
+/// ```
+pub fn parse_french_number(text: &str) -> Result<i128, ParseError> {
+    let mut accumulator = Accumulator::new();
+    let mut last_span = None;
+    for atom in atoms(text) {
+        let lexeme = lexeme(atom.word).ok_or_else(|| ParseError::UnknownWord(atom.span.clone()))?;
+        accumulator.push(lexeme, atom.span.clone())?;
+        last_span = Some(atom.span);
+    }
+    if !accumulator.is_complete() {
+        return Err(last_span.map_or(ParseError::Empty, ParseError::UnexpectedWord));
+    }
+    accumulator.value().ok_or(ParseError::Overflow)
+}
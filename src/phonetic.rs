@@ -0,0 +1,453 @@
+use crate::tokens::Word;
+use crate::{french_tokens, Options, TokenKind};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Write};
+use num_integer::Integer;
+use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
+
+// Pronunciation of the number words before a pause.
+static WORDS: [(&str, &str); 28] = [
+    ("zéro", "zeʁo"),
+    ("un", "œ̃"),
+    ("une", "yn"),
+    ("deux", "dø"),
+    ("trois", "tʁwa"),
+    ("quatre", "katʁ"),
+    ("cinq", "sɛ̃k"),
+    ("six", "sis"),
+    ("sept", "sɛt"),
+    ("huit", "ɥit"),
+    ("neuf", "nœf"),
+    ("dix", "dis"),
+    ("onze", "ɔ̃z"),
+    ("douze", "duz"),
+    ("treize", "tʁɛz"),
+    ("quatorze", "katɔʁz"),
+    ("quinze", "kɛ̃z"),
+    ("seize", "sɛz"),
+    ("vingt", "vɛ̃"),
+    ("vingts", "vɛ̃"),
+    ("trente", "tʁɑ̃t"),
+    ("quarante", "kaʁɑ̃t"),
+    ("cinquante", "sɛ̃kɑ̃t"),
+    ("soixante", "swasɑ̃t"),
+    ("cent", "sɑ̃"),
+    ("cents", "sɑ̃"),
+    ("mille", "mil"),
+    ("et", "e"),
+];
+
+// Pronunciation of the prefixes of the scale nouns, to be followed by
+// `iljɔ̃` or `iljaʁ`.
+static SCALE_PREFIXES: [&str; 16] = [
+    "m",
+    "b",
+    "tʁ",
+    "kwadʁ",
+    "kɛ̃t",
+    "sɛkst",
+    "sɛpt",
+    "ɔkt",
+    "nɔn",
+    "des",
+    "ynodes",
+    "dɥɔdes",
+    "tʁedes",
+    "kwatɥɔʁdes",
+    "kɥɛ̃des",
+    "sɛksdes",
+];
+
+// Nouns starting with an aspirated `h`, which prevents the liaison.
+static ASPIRATED: [&str; 16] = [
+    "hache", "haie", "hall", "hameau", "hamster", "hangar", "haricot", "harpe", "hauteur", "héros",
+    "hibou", "homard", "hongrois", "hotte", "houx", "hublot",
+];
+
+// What follows a word.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Next<'a> {
+    // A pause, at the end of the number.
+    Pause,
+    // A word starting with a consonant.
+    Consonant(&'a str),
+    // A word starting with a vowel, allowing the liaison.
+    Vowel(&'a str),
+}
+
+fn starts_with_vowel(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    let singular = lower.strip_suffix('s').unwrap_or(&lower);
+    match lower.chars().next() {
+        Some('h') => !ASPIRATED.contains(&singular) && !ASPIRATED.contains(&lower.as_str()),
+        Some(c) => "aâàeéèêëiîïoôöuûùüyœæ".contains(c),
+        None => false,
+    }
+}
+
+// The context of a number word, as seen from the words of the number.
+fn next_in_number(word: &str) -> Next<'_> {
+    // `un`, `huit` and `onze` do not allow the liaison of `cent`, but they
+    // start with a vowel as far as `six`, `dix` and `vingt` are concerned.
+    if matches!(word, "un" | "une" | "huit" | "onze" | "et") {
+        Next::Vowel(word)
+    } else {
+        Next::Consonant(word)
+    }
+}
+
+fn is_multiplier(word: &str) -> bool {
+    matches!(
+        word,
+        "deux"
+            | "trois"
+            | "quatre"
+            | "cinq"
+            | "six"
+            | "sept"
+            | "huit"
+            | "neuf"
+            | "dix"
+            | "onze"
+            | "douze"
+            | "treize"
+            | "quatorze"
+            | "quinze"
+            | "seize"
+    )
+}
+
+// Write the pronunciation of a word of the number, given its neighbours and
+// whether the following word is a noun.
+fn pronounce(out: &mut String, word: &str, previous: Option<&str>, next: Next<'_>, noun: bool) {
+    let liaison = matches!(next, Next::Vowel(_)) && noun;
+    let before_consonant = matches!(next, Next::Consonant(_));
+    let base = WORDS
+        .iter()
+        .find(|(w, _)| *w == word)
+        .map_or("", |(_, ipa)| ipa);
+    match word {
+        "six" | "dix" | "huit" | "cinq" if before_consonant => {
+            // `dix-neuf` keeps its final consonant, voiced.
+            if word == "dix" && next == Next::Consonant("neuf") {
+                out.push_str("diz");
+            } else {
+                out.push_str(base.strip_suffix(['s', 't', 'k']).unwrap_or(base));
+            }
+        }
+        "six" | "dix" if matches!(next, Next::Vowel(_)) => {
+            out.push_str(&base[..base.len() - 1]);
+            out.push('z');
+        }
+        "neuf" if liaison && matches!(next, Next::Vowel("ans" | "heures" | "heure")) => {
+            out.push_str("nœv");
+        }
+        "quatre" if before_consonant => out.push_str("katʁə"),
+        "vingt" => {
+            out.push_str(base);
+            let in_tens = matches!(
+                next,
+                Next::Vowel("et" | "un" | "une" | "huit")
+                    | Next::Consonant(
+                        "deux" | "trois" | "quatre" | "cinq" | "six" | "sept" | "neuf"
+                    )
+            );
+            if in_tens && !previous.is_some_and(is_multiplier) || liaison {
+                out.push('t');
+            }
+        }
+        "cent" if liaison => out.push_str("sɑ̃t"),
+        "un" if liaison => out.push_str("œ̃n"),
+        "deux" | "trois" | "vingts" | "cents" if liaison => {
+            out.push_str(base);
+            out.push('z');
+        }
+        _ => out.push_str(base),
+    }
+}
+
+// Split a transcription into phonemes, keeping the diacritics with the
+// letters they modify.
+fn phonemes(ipa: &str) -> Vec<&str> {
+    let mut phonemes = Vec::new();
+    let mut start = 0;
+    for (i, c) in ipa.char_indices().skip(1) {
+        if c != '\u{303}' {
+            phonemes.push(&ipa[start..i]);
+            start = i;
+        }
+    }
+    if start < ipa.len() {
+        phonemes.push(&ipa[start..]);
+    }
+    phonemes
+}
+
+fn is_vowel(phoneme: &str) -> bool {
+    phoneme.starts_with(['a', 'e', 'ɛ', 'i', 'o', 'ɔ', 'u', 'y', 'ø', 'œ', 'ə', 'ɑ'])
+}
+
+// Check whether a sequence of consonants can start a syllable.
+fn is_onset(consonants: &[&str]) -> bool {
+    let glide = |c: &&str| matches!(*c, "j" | "w" | "ɥ");
+    let liquid = |c: &&str| matches!(*c, "ʁ" | "l");
+    let obstruent = |c: &&str| matches!(*c, "p" | "b" | "t" | "d" | "k" | "g" | "f" | "v");
+    let body = match consonants.split_last() {
+        Some((last, body)) if glide(last) => body,
+        _ => consonants,
+    };
+    match body {
+        [] | [_] => true,
+        [first, second] => obstruent(first) && liquid(second),
+        _ => false,
+    }
+}
+
+// Separate the syllables of a transcription with dots, each consonant
+// cluster between two vowels being split so as to give the longest valid
+// onset to the second syllable.
+fn syllabify(ipa: &str) -> String {
+    let phonemes = phonemes(ipa);
+    let vowels = phonemes
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| is_vowel(p))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let mut breaks = Vec::new();
+    for pair in vowels.windows(2) {
+        let cluster = &phonemes[pair[0] + 1..pair[1]];
+        let coda = (0..=cluster.len())
+            .find(|&i| is_onset(&cluster[i..]))
+            .unwrap_or(cluster.len());
+        breaks.push(pair[0] + 1 + coda);
+    }
+    let mut result = String::with_capacity(ipa.len() + breaks.len());
+    for (i, phoneme) in phonemes.iter().enumerate() {
+        if breaks.contains(&i) {
+            result.push('.');
+        }
+        result.push_str(phoneme);
+    }
+    result
+}
+
+// Transcribe the number, without syllable separation, given what follows it.
+fn transcribe<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+    following: Next<'_>,
+) -> Option<String> {
+    let tokens = french_tokens(n, options)?;
+    let mut words = Vec::new();
+    for token in &tokens {
+        match (token.kind, token.word) {
+            (TokenKind::Scale, Word::Scale { log1000, plural }) => {
+                words.push((None, Some((log1000, plural))));
+            }
+            (TokenKind::Sign, _) => words.push((Some(String::from("moins")), None)),
+            _ => {
+                let text = token.to_string();
+                words.extend(text.split('-').map(|w| (Some(String::from(w)), None)));
+            }
+        }
+    }
+    let mut ipa = String::new();
+    for (i, (word, scale)) in words.iter().enumerate() {
+        let next = match words.get(i + 1) {
+            Some((Some(next), _)) => next_in_number(next),
+            Some((None, _)) => Next::Consonant(""),
+            None => following,
+        };
+        let is_last = i + 1 == words.len();
+        let liaison = is_last && matches!(next, Next::Vowel(_));
+        match (word, scale) {
+            (Some(word), _) if word == "moins" => ipa.push_str("mwɛ̃"),
+            (Some(word), _) => {
+                let previous = i.checked_sub(1).and_then(|p| words[p].0.as_deref());
+                pronounce(&mut ipa, word, previous, next, is_last);
+            }
+            (None, Some((log1000, plural))) => {
+                ipa.push_str(SCALE_PREFIXES[log1000 / 2]);
+                ipa.push_str(if log1000 % 2 == 0 {
+                    "iljɔ̃"
+                } else {
+                    "iljaʁ"
+                });
+                if *plural && liaison {
+                    ipa.push('z');
+                }
+            }
+            (None, None) => {}
+        }
+    }
+    Some(ipa)
+}
+
+/// Compute the phonetic transcription, in the International Phonetic
+/// Alphabet, of the French language representation of the given number
+/// with the given formatting options.
+///
+/// The transcription follows the standard pronunciation: the final
+/// consonant of `six`, `dix`, `huit` and `cinq` is silent before a
+/// consonant, and `six` and `dix` are voiced before a vowel, as in
+/// `dix-huit` (/di.zɥit/). The `t` of `vingt` is pronounced from `vingt-et-un`
+/// to `vingt-neuf`, but not in `quatre-vingt-un`. Syllables are separated by
+/// dots, without the enclosing slashes.
+///
+/// When the number is followed by a noun, the pronunciation is adapted to
+/// it. The liaison or the linking with a noun starting with a vowel or a
+/// mute `h` is marked with a final `‿`, as in `deux ans` (/døz‿/) or `neuf
+/// heures` (/nœv‿/). The transcription of the noun is not included.
+///
+/// `None` is returned if the number is too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let options = POST_REFORM_MASCULINE;
+/// assert_eq!(french_number_ipa(&21, &options, None).as_deref(), Some("vɛ̃.te.œ̃"));
+/// assert_eq!(french_number_ipa(&80, &options, None).as_deref(), Some("ka.tʁə.vɛ̃"));
+/// assert_eq!(french_number_ipa(&6, &options, Some("livres")).as_deref(), Some("si"));
+/// assert_eq!(french_number_ipa(&6, &options, Some("euros")).as_deref(), Some("siz‿"));
+/// ```
+pub fn french_number_ipa<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+    noun: Option<&str>,
+) -> Option<String> {
+    let following = noun.map_or(Next::Pause, |noun| {
+        if starts_with_vowel(noun) {
+            Next::Vowel(noun)
+        } else {
+            Next::Consonant(noun)
+        }
+    });
+    let ipa = transcribe(n, options, following)?;
+    let liaison = matches!(following, Next::Vowel(_))
+        && ipa.ends_with(|c: char| !is_vowel(c.encode_utf8(&mut [0; 4])) && c != '\u{303}');
+    let mut result = syllabify(&ipa);
+    if liaison {
+        result.push('‿');
+    }
+    Some(result)
+}
+
+/// Class of the first phoneme of the word following a number, which
+/// determines how the number is pronounced.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NextPhoneme {
+    /// The number is followed by a pause, as in `j'en ai six`.
+    #[default]
+    Pause,
+    /// The next word starts with a consonant or an aspirated `h`, as in
+    /// `six livres` or `six héros`.
+    Consonant,
+    /// The next word starts with a vowel or a mute `h`, as in `six enfants`
+    /// or `six heures`.
+    Vowel,
+}
+
+impl NextPhoneme {
+    /// The class of the first phoneme of a word, guessed from its spelling.
+    /// A word starting with an `h` is considered to start with a vowel
+    /// unless it is a common noun with an aspirated `h`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use french_numbers::NextPhoneme;
+    ///
+    /// assert_eq!(NextPhoneme::of_word("enfants"), NextPhoneme::Vowel);
+    /// assert_eq!(NextPhoneme::of_word("heures"), NextPhoneme::Vowel);
+    /// assert_eq!(NextPhoneme::of_word("héros"), NextPhoneme::Consonant);
+    /// ```
+    #[must_use]
+    pub fn of_word(word: &str) -> Self {
+        if starts_with_vowel(word) {
+            Self::Vowel
+        } else {
+            Self::Consonant
+        }
+    }
+}
+
+/// Pronunciation of a number in a given context.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pronunciation {
+    /// The transcription of the number in the International Phonetic
+    /// Alphabet, including the liaison consonant if any, with syllables
+    /// separated by dots.
+    pub ipa: String,
+    /// The consonant pronounced only because of the liaison with the next
+    /// word, such as the `z` of `six enfants` or the `t` of `cent ans`.
+    pub liaison: Option<char>,
+}
+
+/// The transcription is followed by `‿` when there is a liaison.
+impl Display for Pronunciation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.ipa)?;
+        if self.liaison.is_some() {
+            f.write_char('‿')?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute the pronunciation of the French language representation of the
+/// given number with the given formatting options, according to the first
+/// phoneme of the following word.
+///
+/// Unlike [`french_number_ipa`], this only needs the class of the next
+/// phoneme, as known to a speech synthesizer. The final consonant of `six`,
+/// `dix`, `huit` and `cinq` is silent before a consonant (`six livres`,
+/// /si/), pronounced before a pause (`six`, /sis/), and `six` and `dix` take
+/// a `z` liaison before a vowel (`six enfants`, /siz/). `un`, `deux`,
+/// `trois`, `vingt`, `cent` and the plural scale nouns also take a liaison
+/// before a vowel.
+///
+/// `None` is returned if the number is too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let options = POST_REFORM_MASCULINE;
+/// let six = french_number_pronunciation(&6, &options, NextPhoneme::Vowel).unwrap();
+/// assert_eq!(six.ipa, "siz");
+/// assert_eq!(six.liaison, Some('z'));
+/// let six = french_number_pronunciation(&6, &options, NextPhoneme::Consonant).unwrap();
+/// assert_eq!(six.ipa, "si");
+/// assert_eq!(six.liaison, None);
+/// let cent = french_number_pronunciation(&100, &options, NextPhoneme::of_word("ans")).unwrap();
+/// assert_eq!(cent.to_string(), "sɑ̃t‿");
+/// ```
+pub fn french_number_pronunciation<
+    N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul,
+>(
+    n: &N,
+    options: &Options,
+    next: NextPhoneme,
+) -> Option<Pronunciation> {
+    let following = match next {
+        NextPhoneme::Pause => Next::Pause,
+        NextPhoneme::Consonant => Next::Consonant(""),
+        NextPhoneme::Vowel => Next::Vowel(""),
+    };
+    let ipa = transcribe(n, options, following)?;
+    // The liaison consonant is the one missing before a pause.
+    let liaison = if next == NextPhoneme::Vowel && transcribe(n, options, Next::Pause)? != ipa {
+        ipa.chars().last()
+    } else {
+        None
+    };
+    Some(Pronunciation {
+        ipa: syllabify(&ipa),
+        liaison,
+    })
+}
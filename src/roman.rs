@@ -0,0 +1,240 @@
+use crate::NumeralError;
+#[cfg(feature = "alloc")]
+use crate::{french_number_options, french_ordinal_options, Options};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+/// Context in which a Roman numeral is read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RomanContext {
+    /// A plain cardinal number: `XIV` is read `quatorze`.
+    #[default]
+    Plain,
+    /// A regnal number, read as a cardinal except for the first one:
+    /// `XIV` is read `quatorze` and `Ier` is read `premier`.
+    Regnal,
+    /// A century, or any other ordinal: `XXIe` is read `vingt-et-unième`.
+    Century,
+}
+
+static SYMBOLS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+// Ordinal suffixes which may follow a Roman numeral, the first ones only
+// following the first ordinal.
+#[cfg(feature = "alloc")]
+static FIRST_SUFFIXES: [&str; 5] = ["er", "re", "ère", "ᵉʳ", "ʳᵉ"];
+#[cfg(feature = "alloc")]
+static SUFFIXES: [&str; 4] = ["e", "ème", "eme", "ᵉ"];
+
+/// Write a number between 1 and 3999 as a Roman numeral into a
+/// [`fmt::Write`] sink.
+///
+/// # Errors
+///
+/// An error is returned if the number is out of range, and any error
+/// returned by the sink is propagated.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::write_roman_numeral;
+///
+/// let mut s = String::new();
+/// write_roman_numeral(&mut s, 1789).unwrap();
+/// assert_eq!(s, "MDCCLXXXIX");
+/// ```
+pub fn write_roman_numeral<W: Write>(w: &mut W, n: u32) -> fmt::Result {
+    if !(1..4000).contains(&n) {
+        return Err(fmt::Error);
+    }
+    let mut rest = n;
+    for &(value, symbol) in &SYMBOLS {
+        while rest >= value {
+            w.write_str(symbol)?;
+            rest -= value;
+        }
+    }
+    Ok(())
+}
+
+/// Compute the Roman numeral of a number between 1 and 3999, or `None` if
+/// the number is out of range.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::roman_numeral;
+///
+/// assert_eq!(roman_numeral(14).as_deref(), Some("XIV"));
+/// assert_eq!(roman_numeral(0), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn roman_numeral(n: u32) -> Option<String> {
+    let mut s = String::new();
+    write_roman_numeral(&mut s, n).ok()?;
+    Some(s)
+}
+
+// Letters for the units, fives and tens of each decimal place, from the
+// thousands to the units.
+static PLACES: [(u32, u8, u8, u8); 4] = [
+    (1000, b'M', 0, 0),
+    (100, b'C', b'D', b'M'),
+    (10, b'X', b'L', b'C'),
+    (1, b'I', b'V', b'X'),
+];
+
+// Length of the canonical representation of the digit at the start of the
+// text if it matches, regardless of the case.
+fn digit_len(text: &[u8], digit: u32, (_, one, five, ten): (u32, u8, u8, u8)) -> Option<usize> {
+    let mut pattern = [0u8; 4];
+    let len = match digit {
+        1..=3 => {
+            pattern[..digit as usize].fill(one);
+            digit as usize
+        }
+        4 => {
+            pattern[..2].copy_from_slice(&[one, five]);
+            2
+        }
+        5..=8 => {
+            pattern[0] = five;
+            pattern[1..=digit as usize - 5].fill(one);
+            digit as usize - 4
+        }
+        _ => {
+            pattern[..2].copy_from_slice(&[one, ten]);
+            2
+        }
+    };
+    (pattern[..len].iter().all(|&c| c != 0)
+        && text.len() >= len
+        && text[..len].eq_ignore_ascii_case(&pattern[..len]))
+    .then_some(len)
+}
+
+// Parse the Roman numeral at the start of the text, returning its value
+// and its length.
+fn parse_prefix(text: &str) -> Result<(u32, usize), NumeralError> {
+    if text.is_empty() {
+        return Err(NumeralError::Empty);
+    }
+    let bytes = text.as_bytes();
+    let mut value = 0;
+    let mut pos = 0;
+    for place in PLACES {
+        // The longest match is the right one, as in `VIII` versus `V`.
+        if let Some((digit, len)) = (1..=9)
+            .filter_map(|digit| Some((digit, digit_len(&bytes[pos..], digit, place)?)))
+            .max_by_key(|&(_, len)| len)
+        {
+            value += digit * place.0;
+            pos += len;
+        }
+    }
+    if pos == 0 {
+        return Err(NumeralError::InvalidCharacter(0));
+    }
+    Ok((value, pos))
+}
+
+/// Parse a Roman numeral, in uppercase or lowercase letters.
+///
+/// Only the canonical representations of the numbers from 1 to 3999 are
+/// accepted, so that `IIII` or `IC` are rejected.
+///
+/// # Errors
+///
+/// An error is returned if the text is not a canonical Roman numeral,
+/// pointing at the first unexpected character.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// assert_eq!(parse_roman_numeral("XIV"), Ok(14));
+/// assert_eq!(parse_roman_numeral("mcmxcix"), Ok(1999));
+/// assert_eq!(parse_roman_numeral("IIII"), Err(NumeralError::InvalidCharacter(3)));
+/// ```
+pub fn parse_roman_numeral(text: &str) -> Result<u32, NumeralError> {
+    let (value, len) = parse_prefix(text)?;
+    if len < text.len() {
+        return Err(NumeralError::InvalidCharacter(len));
+    }
+    Ok(value)
+}
+
+/// Compute the French language representation of a Roman numeral read in
+/// the given context.
+///
+/// In the regnal and century contexts, the numeral may be followed by an
+/// ordinal suffix: `Ier` or `Ire` for the first one, `e`, `ème` or the
+/// superscript `ᵉ` for the others. The first one is read `première` when
+/// its suffix is feminine or when the options request the feminine.
+///
+/// # Errors
+///
+/// An error is returned if the text is not a canonical Roman numeral
+/// followed by an ordinal suffix allowed in this context.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let options = POST_REFORM_MASCULINE;
+/// assert_eq!(french_roman_number("XIV", RomanContext::Regnal, &options).unwrap(), "quatorze");
+/// assert_eq!(french_roman_number("Ier", RomanContext::Regnal, &options).unwrap(), "premier");
+/// assert_eq!(french_roman_number("XXIe", RomanContext::Century, &options).unwrap(),
+///            "vingt-et-unième");
+/// assert_eq!(french_roman_number("MCMXCIX", RomanContext::Plain, &PRE_REFORM_MASCULINE)
+///                .unwrap(),
+///            "mille neuf cent quatre-vingt-dix-neuf");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn french_roman_number(
+    text: &str,
+    context: RomanContext,
+    options: &Options,
+) -> Result<String, NumeralError> {
+    let (value, len) = parse_prefix(text)?;
+    let suffix = &text[len..];
+    let accepted = suffix.is_empty()
+        || context != RomanContext::Plain
+            && if value == 1 {
+                FIRST_SUFFIXES.contains(&suffix)
+            } else {
+                context == RomanContext::Century && SUFFIXES.contains(&suffix)
+            };
+    if !accepted {
+        return Err(NumeralError::InvalidCharacter(len));
+    }
+    let options = Options {
+        feminine: options.feminine || matches!(suffix, "re" | "ère" | "ʳᵉ"),
+        ..*options
+    };
+    Ok(match context {
+        RomanContext::Regnal if value > 1 => french_number_options(&value, &options),
+        RomanContext::Plain => french_number_options(&value, &options),
+        RomanContext::Regnal | RomanContext::Century => {
+            french_ordinal_options(&value, &options).expect("numbers below 4000 have an ordinal")
+        }
+    })
+}
@@ -0,0 +1,564 @@
+use crate::orthography::conventions;
+use crate::parse::{eq_ignore_case, is_separator, lexeme, text_words, Accumulator, Atom, Lexeme};
+use crate::{Convention, Options};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Kind of a number found in a text.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+    /// A cardinal number, such as `vingt-deux` or `1 234`.
+    Cardinal,
+    /// An ordinal number, such as `vingt-deuxième` or `22e`.
+    Ordinal,
+    /// A decimal number, such as `deux virgule cinq` or `1 234,56`.
+    Decimal,
+    /// A fraction, such as `trois quarts` or `deux centièmes`.
+    Fraction,
+}
+
+/// A number found in a French text, written in words or in digits.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExtractedNumber {
+    /// The byte range of the number in the text.
+    pub span: Range<usize>,
+    /// The kind of the number.
+    pub kind: NumberKind,
+    /// The numerator of the value of the number.
+    pub numerator: i128,
+    /// The denominator of the value of the number, always strictly positive.
+    /// It is `1` for cardinal and ordinal numbers, and a power of ten for
+    /// decimal numbers.
+    pub denominator: i128,
+    /// The options matching the way the number is written, or `None` if it
+    /// is written in digits. When the text does not allow to decide, for
+    /// example in `vingt-deux`, the default options are assumed.
+    pub options: Option<Options>,
+}
+
+/// Find the numbers written in a French text, either in words or in digits.
+///
+/// Digits may be grouped by three with spaces, no-break spaces or narrow
+/// no-break spaces, and use a decimal comma. They may be followed by an
+/// ordinal suffix (`1er`, `2e`, `3ème`) or by a scale noun (`2,5 millions`).
+/// Digits sticking to letters or to other digits through punctuation, such
+/// as in `A320`, `21/03/2026` or `10:30`, are considered as codes and
+/// ignored, and so are numbers too large to fit into an `i128`.
+///
+/// Numbers written in words may be cardinals, ordinals, decimals
+/// (`deux virgule cinq`) or fractions (`trois quarts`). Since `un` and `une`
+/// are also articles, they are only reported as numbers on their own when
+/// they are connected to another number, as in `un ou deux`. Similarly,
+/// `moins` is not considered as a sign after `au`, `du`, `le` and similar
+/// words, as in `au moins deux`, and `cents` and `vingts` are only numbers
+/// after a multiplier, unlike in `cinquante cents`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let text = "Le 1er mai, trois quarts des 1 234 votants ont dit vingt-et-un.";
+/// let numbers = extract_french_numbers(text);
+/// let found = numbers.iter().map(|n| (&text[n.span.clone()], n.kind, n.numerator, n.denominator))
+///     .collect::<Vec<_>>();
+/// assert_eq!(found, [
+///     ("1er", NumberKind::Ordinal, 1, 1),
+///     ("trois quarts", NumberKind::Fraction, 3, 4),
+///     ("1 234", NumberKind::Cardinal, 1234, 1),
+///     ("vingt-et-un", NumberKind::Cardinal, 21, 1),
+/// ]);
+/// assert_eq!(numbers[3].options, Some(POST_REFORM_MASCULINE));
+/// ```
+#[must_use]
+pub fn extract_french_numbers(text: &str) -> Vec<ExtractedNumber> {
+    let mut numbers = find_digits(text);
+    let taken = numbers.iter().map(|n| n.span.clone()).collect::<Vec<_>>();
+    // Words that are part of a number in digits, such as `millions` in
+    // `2,5 millions`, are blanked so that they stop the numbers in words.
+    let words = text_words(text)
+        .map(|mut w| {
+            if taken.iter().any(|t| t.contains(&w.span.start)) {
+                w.word = "";
+            }
+            w
+        })
+        .collect::<Vec<_>>();
+    let mut ambiguous = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let Some(found) = match_words(text, &words[i..]).filter(|_| !is_adverb(&words, i)) else {
+            i += 1;
+            continue;
+        };
+        let span = words[i].span.start..words[i + found.len - 1].span.end;
+        if found.len == 1 && found.kind == NumberKind::Cardinal && found.numerator == 1 {
+            ambiguous.push(span.start);
+        }
+        numbers.push(ExtractedNumber {
+            options: Some(detect_options(&text[span.clone()])),
+            span,
+            kind: found.kind,
+            numerator: found.numerator,
+            denominator: found.denominator,
+        });
+        i += found.len;
+    }
+    numbers.sort_unstable_by_key(|n| n.span.start);
+    let connected = |a: &ExtractedNumber, b: &ExtractedNumber| {
+        ["ou", "à", "et", "sur"]
+            .iter()
+            .any(|c| eq_ignore_case(text[a.span.end..b.span.start].trim(), c))
+    };
+    let is_ambiguous = |n: &ExtractedNumber| ambiguous.contains(&n.span.start);
+    let keep = (0..numbers.len())
+        .map(|i| {
+            let (number, next) = (&numbers[i], numbers.get(i + 1));
+            !is_ambiguous(number)
+                || i > 0 && !is_ambiguous(&numbers[i - 1]) && connected(&numbers[i - 1], number)
+                || next.is_some_and(|next| !is_ambiguous(next) && connected(number, next))
+        })
+        .collect::<Vec<_>>();
+    let mut keep = keep.into_iter();
+    numbers.retain(|_| keep.next().unwrap_or(false));
+    numbers
+}
+
+// A number spelled in words found in a running text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SpelledNumber {
+    pub(crate) span: Range<usize>,
+    pub(crate) value: i128,
+}
+
+// Find the cardinal and ordinal numbers spelled in words in a text.
+pub(crate) fn find_spelled_numbers(text: &str) -> Vec<SpelledNumber> {
+    extract_french_numbers(text)
+        .into_iter()
+        .filter(|n| {
+            n.options.is_some() && matches!(n.kind, NumberKind::Cardinal | NumberKind::Ordinal)
+        })
+        .map(|n| SpelledNumber {
+            span: n.span,
+            value: n.numerator,
+        })
+        .collect()
+}
+
+// Return the lexeme corresponding to a cardinal or ordinal number word.
+pub(crate) fn word_lexeme(word: &str) -> Option<Lexeme> {
+    lexeme(word).or_else(|| ordinal_lexeme(word).map(|(lexeme, _)| lexeme))
+}
+
+// Return the lexeme at the root of an ordinal word such as `cinquième`, and
+// whether the word is in the plural. `premier` is not handled here since it
+// only appears on its own.
+fn ordinal_lexeme(word: &str) -> Option<(Lexeme, bool)> {
+    let lower = word.to_lowercase();
+    let (singular, plural) = lower
+        .strip_suffix('s')
+        .map_or((lower.as_str(), false), |s| (s, true));
+    let stem = singular.strip_suffix("ième")?;
+    let root = match stem {
+        "cinqu" => Some(Lexeme::Number(5)),
+        "neuv" => Some(Lexeme::Number(9)),
+        _ => lexeme(stem).or_else(|| lexeme(&(String::from(stem) + "e"))),
+    };
+    root.filter(|l| !matches!(l, Lexeme::Moins | Lexeme::Et | Lexeme::Number(0)))
+        .map(|l| (l, plural))
+}
+
+fn is_premier(word: &str) -> bool {
+    ["premier", "premiers", "première", "premières"]
+        .iter()
+        .any(|p| eq_ignore_case(word, p))
+}
+
+// Check whether the word at the given index is a `moins` used as an adverb.
+fn is_adverb(words: &[Atom], i: usize) -> bool {
+    eq_ignore_case(words[i].word, "moins")
+        && i > 0
+        && ["au", "du", "le", "la", "les", "de", "pas", "ou", "en"]
+            .iter()
+            .any(|w| eq_ignore_case(words[i - 1].word, w))
+}
+
+// The longest cardinal and ordinal numbers starting at the first word.
+#[derive(Debug, Default)]
+struct Candidates {
+    negative: bool,
+    // Number of words and value.
+    cardinal: Option<(usize, i128)>,
+    // Number of words, value and plural.
+    ordinal: Option<(usize, i128, bool)>,
+}
+
+fn candidates(text: &str, words: &[Atom]) -> Candidates {
+    let mut result = Candidates::default();
+    let mut accumulator = Accumulator::new();
+    let mut previous = None;
+    for (i, word) in words.iter().enumerate() {
+        let joiner = &text[word.joiner.clone()];
+        if i > 0 && (joiner.is_empty() || !joiner.chars().all(is_separator)) {
+            break;
+        }
+        if i == 0 && is_premier(word.word) {
+            result.ordinal = Some((1, 1, word.word.ends_with('s')));
+            break;
+        }
+        if let Some((lexeme, plural)) = ordinal_lexeme(word.word) {
+            if accumulator.push(lexeme, word.span.clone()).is_ok() && accumulator.is_complete() {
+                result.ordinal = accumulator
+                    .value()
+                    .filter(|&v| v > 1)
+                    .map(|v| (i + 1, v, plural));
+            }
+            break;
+        }
+        let Some(lexeme) = lexeme(word.word) else {
+            break;
+        };
+        // The plural `cents` and `vingts` need a multiplier, otherwise they
+        // are rather nouns, as in `cinquante cents`.
+        let multiplied = matches!(previous, Some(Lexeme::Number(2..=19)));
+        if !multiplied
+            && ["cents", "vingts"]
+                .iter()
+                .any(|w| eq_ignore_case(word.word, w))
+        {
+            break;
+        }
+        if accumulator.push(lexeme, word.span.clone()).is_err() {
+            break;
+        }
+        previous = Some(lexeme);
+        result.negative |= lexeme == Lexeme::Moins;
+        if accumulator.is_complete() {
+            if let Some(value) = accumulator.value() {
+                result.cardinal = Some((i + 1, value));
+            }
+        }
+    }
+    result
+}
+
+// A number found in a sequence of words.
+struct WordMatch {
+    len: usize,
+    kind: NumberKind,
+    numerator: i128,
+    denominator: i128,
+}
+
+fn match_words(text: &str, words: &[Atom]) -> Option<WordMatch> {
+    let found = candidates(text, words);
+    if let Some((len, value)) = found.cardinal {
+        if let Some(decimal) = decimal(text, words, len, value, found.negative) {
+            return Some(decimal);
+        }
+        if let Some(fraction) = fraction(text, words, len, value) {
+            return Some(fraction);
+        }
+    }
+    match (found.cardinal, found.ordinal) {
+        (_, Some((len, value, _))) if found.cardinal.is_none_or(|(l, _)| l < len) => {
+            Some(WordMatch {
+                len,
+                kind: NumberKind::Ordinal,
+                numerator: value,
+                denominator: 1,
+            })
+        }
+        (Some((len, value)), _) => Some(WordMatch {
+            len,
+            kind: NumberKind::Cardinal,
+            numerator: value,
+            denominator: 1,
+        }),
+        _ => None,
+    }
+}
+
+// Check whether the word at the given index follows the previous one after
+// some whitespace.
+fn spaced(text: &str, words: &[Atom], i: usize) -> bool {
+    words.get(i).is_some_and(|w| {
+        let joiner = &text[w.joiner.clone()];
+        !joiner.is_empty() && joiner.chars().all(char::is_whitespace)
+    })
+}
+
+// Recognize a decimal number such as `deux virgule zéro cinq`, whose integral
+// part is made of the given number of words.
+fn decimal(
+    text: &str,
+    words: &[Atom],
+    len: usize,
+    value: i128,
+    negative: bool,
+) -> Option<WordMatch> {
+    if !spaced(text, words, len) || !eq_ignore_case(words[len].word, "virgule") {
+        return None;
+    }
+    let mut end = len + 1;
+    while spaced(text, words, end) && lexeme(words[end].word) == Some(Lexeme::Number(0)) {
+        end += 1;
+    }
+    let zeros = end - len - 1;
+    let mut fractional = 0;
+    let mut scale = zeros;
+    if spaced(text, words, end) {
+        if let Some((l, v)) = candidates(text, &words[end..])
+            .cardinal
+            .filter(|&(_, v)| v > 0)
+        {
+            fractional = v;
+            scale += v.ilog10() as usize + 1;
+            end += l;
+        }
+    }
+    if end == len + 1 {
+        return None;
+    }
+    let denominator = 10i128.checked_pow(u32::try_from(scale).ok()?)?;
+    let magnitude = value
+        .unsigned_abs()
+        .checked_mul(denominator.unsigned_abs())?
+        .checked_add(fractional.unsigned_abs())?;
+    let numerator = if negative {
+        0i128.checked_sub_unsigned(magnitude)?
+    } else {
+        i128::try_from(magnitude).ok()?
+    };
+    Some(WordMatch {
+        len: end,
+        kind: NumberKind::Decimal,
+        numerator,
+        denominator,
+    })
+}
+
+// Recognize a fraction such as `trois quarts`, whose numerator is made of the
+// given number of words.
+fn fraction(text: &str, words: &[Atom], len: usize, numerator: i128) -> Option<WordMatch> {
+    if numerator <= 0 || !spaced(text, words, len) {
+        return None;
+    }
+    let word = words[len].word;
+    let special = [
+        ("demi", 2),
+        ("demie", 2),
+        ("demis", 2),
+        ("demies", 2),
+        ("tiers", 3),
+        ("quart", 4),
+        ("quarts", 4),
+    ];
+    let (denominator_len, denominator) =
+        if let Some(&(_, d)) = special.iter().find(|(w, _)| eq_ignore_case(word, w)) {
+            (1, d)
+        } else {
+            let (l, d, plural) = candidates(text, &words[len..]).ordinal?;
+            if numerator > 1 && !plural {
+                return None;
+            }
+            (l, d)
+        };
+    Some(WordMatch {
+        len: len + denominator_len,
+        kind: NumberKind::Fraction,
+        numerator,
+        denominator,
+    })
+}
+
+// Guess the options used to write a number in words.
+fn detect_options(number: &str) -> Options {
+    let conventions = conventions(number);
+    let last = text_words(number).last().map_or("", |w| w.word);
+    Options {
+        feminine: eq_ignore_case(last, "une")
+            || eq_ignore_case(last, "première")
+            || eq_ignore_case(last, "premières")
+            || eq_ignore_case(last, "demie"),
+        reformed: conventions.contains(&Convention::Reformed)
+            || !conventions.contains(&Convention::Traditional),
+    }
+}
+
+pub(crate) fn is_group_separator(c: char) -> bool {
+    matches!(c, ' ' | '\u{a0}' | '\u{202f}')
+}
+
+fn is_code_punctuation(c: char) -> bool {
+    matches!(c, '.' | ',' | '/' | ':' | '-' | '_')
+}
+
+// Find the numbers written in digits in a text.
+fn find_digits(text: &str) -> Vec<ExtractedNumber> {
+    let mut numbers = Vec::new();
+    let mut pos = 0;
+    while let Some(offset) = text[pos..].find(|c: char| c.is_ascii_digit()) {
+        let start = pos + offset;
+        let mut before = text[..start].chars().rev();
+        let previous = before.next();
+        let code = match previous {
+            Some(c) if c.is_alphanumeric() => true,
+            Some(c) if is_code_punctuation(c) || c == '−' => {
+                before.next().is_some_and(char::is_alphanumeric)
+            }
+            _ => false,
+        };
+        let negative = matches!(previous, Some('-' | '−')) && !code;
+        match (!code).then(|| digits_at(text, start, negative)).flatten() {
+            Some(mut number) => {
+                if negative {
+                    number.span.start -= previous.map_or(0, char::len_utf8);
+                }
+                pos = number.span.end;
+                numbers.push(number);
+            }
+            None => {
+                // Skip the whole number, so that the groups of digits of a
+                // number too large to fit are not taken as numbers.
+                pos = (start
+                    + text[start..]
+                        .find(|c: char| !c.is_alphanumeric())
+                        .unwrap_or(text.len() - start))
+                .max(grouped_end(text, start));
+            }
+        }
+    }
+    numbers
+}
+
+// Return the length of the run of ASCII digits starting at the given position.
+fn digits_run(text: &str, start: usize) -> usize {
+    text[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len() - start)
+}
+
+// Return the end of the digits starting at the given position, including
+// the following groups of three digits if they are grouped.
+fn grouped_end(text: &str, start: usize) -> usize {
+    let mut end = start + digits_run(text, start);
+    if end - start <= 3 {
+        while let Some(separator) = text[end..]
+            .chars()
+            .next()
+            .filter(|&c| is_group_separator(c))
+        {
+            let group = end + separator.len_utf8();
+            if digits_run(text, group) != 3 {
+                break;
+            }
+            end = group + 3;
+        }
+    }
+    end
+}
+
+// Accumulate the digits of the text, ignoring the group separators.
+fn accumulate(value: i128, digits: &str) -> Option<i128> {
+    digits
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .try_fold(value, |v, d| {
+            v.checked_mul(10)?.checked_add(i128::from(d - b'0'))
+        })
+}
+
+// Parse a number written in digits starting at the given position.
+fn digits_at(text: &str, start: usize, negative: bool) -> Option<ExtractedNumber> {
+    let mut end = grouped_end(text, start);
+    let mut numerator = accumulate(0, &text[start..end])?;
+    let mut kind = NumberKind::Cardinal;
+    let mut denominator = 1i128;
+    if text[end..].starts_with(',') {
+        let fractional = end + 1;
+        let run = digits_run(text, fractional);
+        let after = &text[fractional + run..];
+        if run > 0 && !(after.starts_with(',') && digits_run(after, 1) > 0) {
+            numerator = accumulate(numerator, &text[fractional..fractional + run])?;
+            denominator = 10i128.checked_pow(u32::try_from(run).ok()?)?;
+            kind = NumberKind::Decimal;
+            end = fractional + run;
+        }
+    }
+    let mut rest = text[end..].chars();
+    match rest.next() {
+        Some(c) if c.is_alphanumeric() => {
+            if kind != NumberKind::Cardinal || negative {
+                return None;
+            }
+            end += ordinal_suffix(&text[end..], numerator)?;
+            kind = NumberKind::Ordinal;
+        }
+        Some(c) if is_code_punctuation(c) && rest.next().is_some_and(char::is_alphanumeric) => {
+            return None;
+        }
+        _ => (),
+    }
+    if kind != NumberKind::Ordinal {
+        if let Some((scale_end, log1000)) = scale_after(text, end) {
+            numerator = 1000i128
+                .checked_pow(log1000 as u32 + 2)
+                .and_then(|scale| numerator.checked_mul(scale))?;
+            while denominator > 1 && numerator % 10 == 0 {
+                numerator /= 10;
+                denominator /= 10;
+            }
+            if denominator == 1 {
+                kind = NumberKind::Cardinal;
+            }
+            end = scale_end;
+        }
+    }
+    Some(ExtractedNumber {
+        span: start..end,
+        kind,
+        numerator: if negative { -numerator } else { numerator },
+        denominator,
+        options: None,
+    })
+}
+
+// Return the length of the ordinal suffix at the start of the text, if any.
+fn ordinal_suffix(text: &str, value: i128) -> Option<usize> {
+    let suffixes: &[&str] = match value {
+        0 => &[],
+        1 => &["er", "ers", "re", "res", "ère", "ères", "ᵉʳ", "ʳᵉ"],
+        2 => &["e", "es", "ème", "èmes", "ᵉ", "nd", "nds", "nde", "ndes"],
+        _ => &["e", "es", "ème", "èmes", "ᵉ"],
+    };
+    suffixes
+        .iter()
+        .filter(|s| {
+            text.starts_with(*s)
+                && !text[s.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_alphanumeric)
+        })
+        .map(|s| s.len())
+        .max()
+}
+
+// Recognize a scale noun, such as `millions`, following a number written in
+// digits, and return its end and its power of thousand.
+fn scale_after(text: &str, end: usize) -> Option<(usize, usize)> {
+    let rest = &text[end..];
+    let start = end + rest.find(|c: char| !c.is_whitespace())?;
+    if start == end {
+        return None;
+    }
+    let word_end = text[start..]
+        .find(|c: char| !c.is_alphabetic())
+        .map_or(text.len(), |i| start + i);
+    match lexeme(&text[start..word_end])? {
+        Lexeme::Scale(log1000) => Some((word_end, log1000)),
+        _ => None,
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{french_number_options, french_ordinal_options, Options};
+use alloc::string::String;
+use core::num::{NonZero, Saturating, Wrapping};
+
+/// Conversion of a number into its French language representation.
+///
+/// This trait is implemented for all primitive integer types, as well as
+/// their [`NonZero`], [`Wrapping`] and [`Saturating`] counterparts. When the
+/// `num-bigint` feature is enabled, it is also implemented for
+/// `num_bigint::BigInt` and `num_bigint::BigUint`.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::{ToFrench, PRE_REFORM_MASCULINE};
+/// use std::num::NonZeroU32;
+///
+/// assert_eq!(71.to_french(), "soixante-et-onze");
+/// assert_eq!(180u8.to_french_with(&PRE_REFORM_MASCULINE), "cent quatre-vingts");
+/// assert_eq!(NonZeroU32::new(3).unwrap().to_french_ordinal().as_deref(), Some("troisième"));
+/// ```
+pub trait ToFrench {
+    /// Compute the French language representation with the default options.
+    /// See [`french_number`](crate::french_number).
+    fn to_french(&self) -> String {
+        self.to_french_with(&Options::default())
+    }
+
+    /// Compute the French language representation with the given options.
+    /// See [`french_number_options`].
+    fn to_french_with(&self, options: &Options) -> String;
+
+    /// Compute the French language ordinal representation with the default
+    /// options. See [`french_ordinal`](crate::french_ordinal).
+    fn to_french_ordinal(&self) -> Option<String> {
+        self.to_french_ordinal_with(&Options::default())
+    }
+
+    /// Compute the French language ordinal representation with the given
+    /// options. See [`french_ordinal_options`].
+    fn to_french_ordinal_with(&self, options: &Options) -> Option<String>;
+}
+
+macro_rules! impl_to_french {
+    ($($t:ty),*) => {
+        $(
+            impl ToFrench for $t {
+                fn to_french_with(&self, options: &Options) -> String {
+                    french_number_options(self, options)
+                }
+
+                fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+                    french_ordinal_options(self, options)
+                }
+            }
+
+            impl ToFrench for NonZero<$t> {
+                fn to_french_with(&self, options: &Options) -> String {
+                    self.get().to_french_with(options)
+                }
+
+                fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+                    self.get().to_french_ordinal_with(options)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_french!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: ToFrench> ToFrench for Wrapping<T> {
+    fn to_french_with(&self, options: &Options) -> String {
+        self.0.to_french_with(options)
+    }
+
+    fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+        self.0.to_french_ordinal_with(options)
+    }
+}
+
+impl<T: ToFrench> ToFrench for Saturating<T> {
+    fn to_french_with(&self, options: &Options) -> String {
+        self.0.to_french_with(options)
+    }
+
+    fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+        self.0.to_french_ordinal_with(options)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToFrench for num_bigint::BigInt {
+    fn to_french_with(&self, options: &Options) -> String {
+        french_number_options(self, options)
+    }
+
+    fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+        french_ordinal_options(self, options)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl ToFrench for num_bigint::BigUint {
+    fn to_french_with(&self, options: &Options) -> String {
+        french_number_options(self, options)
+    }
+
+    fn to_french_ordinal_with(&self, options: &Options) -> Option<String> {
+        french_ordinal_options(self, options)
+    }
+}
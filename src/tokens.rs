@@ -0,0 +1,161 @@
+use crate::add_unit_for;
+#[cfg(feature = "alloc")]
+use crate::{emit_french_number, Options};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+use core::ops::Range;
+#[cfg(feature = "alloc")]
+use num_integer::Integer;
+#[cfg(feature = "alloc")]
+use num_traits::{CheckedMul, FromPrimitive, ToPrimitive};
+
+/// Kind of a word in the French representation of a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// The `moins` sign of a negative number.
+    Sign,
+    /// A unit, from `zéro` to `neuf`.
+    Unit,
+    /// A number from `dix` to `dix-neuf`.
+    Teen,
+    /// A multiple of ten, from `vingt` to `quatre-vingts`.
+    Tens,
+    /// The `et` conjunction, as in `vingt-et-un`.
+    Et,
+    /// The `cent` word, possibly in plural form.
+    Hundred,
+    /// The `mille` word.
+    Thousand,
+    /// A scale noun such as `million` or `milliard`, possibly in plural form.
+    Scale,
+}
+
+/// Joiner placed before a word in the French representation of a number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Joiner {
+    /// No joiner, used before the first word.
+    None,
+    /// A hyphen.
+    Hyphen,
+    /// A space.
+    Space,
+}
+
+impl Joiner {
+    /// The text of the joiner.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "",
+            Self::Hyphen => "-",
+            Self::Space => " ",
+        }
+    }
+}
+
+impl Display for Joiner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Word {
+    Literal(&'static str),
+    Scale { log1000: usize, plural: bool },
+}
+
+/// A word of the French representation of a number.
+///
+/// The [`Display`] implementation writes the word itself, without its joiner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Token {
+    /// The kind of word.
+    pub kind: TokenKind,
+    /// The joiner placed before the word.
+    pub joiner: Joiner,
+    /// The positions of the digits this word stands for, in the decimal
+    /// representation of the absolute value of the number, the most
+    /// significant digit being at position 0. This range is empty for the
+    /// sign and for `et`. A teen following `soixante` or `quatre-vingt`, as
+    /// in `soixante-douze`, only stands for the units digit.
+    pub digits: Range<usize>,
+    pub(crate) word: Word,
+}
+
+impl Token {
+    /// Check whether the word is in plural form, such as `vingts`,
+    /// `cents` or `millions`.
+    #[must_use]
+    pub fn is_plural(&self) -> bool {
+        match self.word {
+            Word::Literal(word) => {
+                matches!(self.kind, TokenKind::Tens | TokenKind::Hundred) && word.ends_with('s')
+            }
+            Word::Scale { plural, .. } => plural,
+        }
+    }
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.word {
+            Word::Literal(word) => f.write_str(word),
+            Word::Scale { log1000, plural } => add_unit_for(f, usize::from(plural) + 1, log1000),
+        }
+    }
+}
+
+/// Compute the words making up the French language representation of the
+/// given number with the given formatting options.
+///
+/// `None` is returned if the number is too large to be spelled.
+///
+/// # Example
+///
+/// ```
+/// use french_numbers::*;
+///
+/// let tokens = french_tokens(&1_081, &POST_REFORM_MASCULINE).unwrap();
+/// let words = tokens.iter().map(ToString::to_string).collect::<Vec<_>>();
+/// assert_eq!(words, ["mille", "quatre-vingt", "un"]);
+/// assert_eq!(tokens[1].kind, TokenKind::Tens);
+/// assert_eq!(tokens[1].joiner, Joiner::Hyphen);
+/// assert_eq!(tokens[1].digits, 2..3);
+/// assert_eq!(render_tokens(&tokens), "mille-quatre-vingt-un");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn french_tokens<N: Integer + FromPrimitive + ToPrimitive + Display + CheckedMul>(
+    n: &N,
+    options: &Options,
+) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    emit_french_number(n, options, None, |token| {
+        tokens.push(token);
+        Ok(())
+    })
+    .ok()?
+    .then_some(tokens)
+}
+
+/// Write the given words, with their joiners, into a [`fmt::Write`] sink.
+///
+/// # Errors
+///
+/// Any error returned by the sink is propagated.
+pub fn write_tokens<W: Write>(w: &mut W, tokens: &[Token]) -> fmt::Result {
+    for token in tokens {
+        write!(w, "{}{token}", token.joiner)?;
+    }
+    Ok(())
+}
+
+/// Build a string from the given words and their joiners.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn render_tokens(tokens: &[Token]) -> String {
+    let mut s = String::new();
+    write_tokens(&mut s, tokens).expect("writing into a String cannot fail");
+    s
+}
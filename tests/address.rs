@@ -0,0 +1,91 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_street_number, parse_french_street_number, AddressSuffix, ParseError, StreetNumber,
+    POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE,
+};
+
+fn street(number: u32, rank: u32) -> StreetNumber {
+    StreetNumber {
+        number,
+        suffix: AddressSuffix::from_rank(rank),
+    }
+}
+
+#[test]
+fn test_suffixes() {
+    let names = [
+        "bis",
+        "ter",
+        "quater",
+        "quinquies",
+        "sexies",
+        "septies",
+        "octies",
+        "nonies",
+        "decies",
+    ];
+    for (rank, name) in (2..).zip(names) {
+        let suffix = AddressSuffix::from_rank(rank).unwrap();
+        assert_eq!(suffix.rank(), rank);
+        assert_eq!(suffix.as_str(), name);
+        assert_eq!(AddressSuffix::from_word(name), Some(suffix));
+    }
+    assert_eq!(AddressSuffix::from_rank(0), None);
+    assert_eq!(AddressSuffix::from_rank(1), None);
+    assert_eq!(AddressSuffix::from_rank(11), None);
+    assert_eq!(AddressSuffix::from_word("TER"), Some(AddressSuffix::Ter));
+    assert_eq!(AddressSuffix::from_word("b"), None);
+}
+
+#[test]
+fn test_spelling() {
+    assert_eq!(
+        french_street_number(&street(12, 2), &POST_REFORM_MASCULINE),
+        "douze bis"
+    );
+    assert_eq!(
+        french_street_number(&street(71, 10), &PRE_REFORM_MASCULINE),
+        "soixante et onze decies"
+    );
+    assert_eq!(
+        french_street_number(&street(7, 1), &POST_REFORM_MASCULINE),
+        "sept"
+    );
+    assert_eq!(street(3, 4).to_string(), "3 quater");
+    assert_eq!(street(3, 1).to_string(), "3");
+}
+
+#[test]
+fn test_parsing() {
+    assert_eq!(parse_french_street_number("7 ter"), Ok(street(7, 3)));
+    assert_eq!(parse_french_street_number(" 7Ter "), Ok(street(7, 3)));
+    assert_eq!(parse_french_street_number("9 nonies"), Ok(street(9, 9)));
+    assert_eq!(
+        parse_french_street_number("vingt-et-un quinquies"),
+        Ok(street(21, 5))
+    );
+    assert_eq!(parse_french_street_number("cent"), Ok(street(100, 1)));
+    assert_eq!("12 sexies".parse(), Ok(street(12, 6)));
+    assert_eq!(parse_french_street_number(""), Err(ParseError::Empty));
+    assert_eq!(
+        parse_french_street_number("bis"),
+        Err(ParseError::UnknownWord(0..3))
+    );
+    assert_eq!(
+        parse_french_street_number("0 bis"),
+        Err(ParseError::UnexpectedWord(0..1))
+    );
+    assert_eq!(
+        parse_french_street_number("moins un bis"),
+        Err(ParseError::UnexpectedWord(0..8))
+    );
+    assert_eq!(
+        parse_french_street_number("douze bus"),
+        Err(ParseError::UnknownWord(6..9))
+    );
+    assert_eq!(
+        parse_french_street_number("99999999999 bis"),
+        Err(ParseError::Overflow)
+    );
+}
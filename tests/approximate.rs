@@ -0,0 +1,122 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    approximate_french_number, ApproximateOptions, EnvironPolicy, Rounding, POST_REFORM_FEMININE,
+};
+
+fn never() -> ApproximateOptions {
+    ApproximateOptions {
+        environ: EnvironPolicy::Never,
+        ..ApproximateOptions::default()
+    }
+}
+
+#[test]
+fn test_nearest() {
+    let options = never();
+    assert_eq!(approximate_french_number(0, &options), "zéro");
+    assert_eq!(approximate_french_number(8, &options), "huit");
+    assert_eq!(approximate_french_number(9, &options), "une dizaine");
+    assert_eq!(approximate_french_number(11, &options), "une douzaine");
+    assert_eq!(approximate_french_number(14, &options), "une quinzaine");
+    assert_eq!(approximate_french_number(25, &options), "une trentaine");
+    assert_eq!(approximate_french_number(44, &options), "une quarantaine");
+    assert_eq!(approximate_french_number(79, &options), "une soixantaine");
+    assert_eq!(approximate_french_number(80, &options), "une centaine");
+    assert_eq!(approximate_french_number(149, &options), "une centaine");
+    assert_eq!(approximate_french_number(150, &options), "des centaines");
+    assert_eq!(approximate_french_number(899, &options), "des centaines");
+    assert_eq!(approximate_french_number(900, &options), "un millier");
+    assert_eq!(approximate_french_number(1_400, &options), "un millier");
+    assert_eq!(approximate_french_number(250_000, &options), "des milliers");
+    assert_eq!(approximate_french_number(950_000, &options), "un million");
+    assert_eq!(
+        approximate_french_number(3_200_000, &options),
+        "des millions"
+    );
+    assert_eq!(
+        approximate_french_number(1_100_000_000, &options),
+        "un milliard"
+    );
+    assert_eq!(
+        approximate_french_number(u128::MAX, &options),
+        "des milliards"
+    );
+}
+
+#[test]
+fn test_rounding() {
+    let down = ApproximateOptions {
+        rounding: Rounding::Down,
+        ..never()
+    };
+    assert_eq!(approximate_french_number(9, &down), "neuf");
+    assert_eq!(approximate_french_number(19, &down), "une quinzaine");
+    assert_eq!(approximate_french_number(199, &down), "une centaine");
+    assert_eq!(approximate_french_number(999, &down), "des centaines");
+    let up = ApproximateOptions {
+        rounding: Rounding::Up,
+        ..never()
+    };
+    assert_eq!(approximate_french_number(0, &up), "zéro");
+    assert_eq!(approximate_french_number(3, &up), "une dizaine");
+    assert_eq!(approximate_french_number(21, &up), "une trentaine");
+    assert_eq!(approximate_french_number(101, &up), "des centaines");
+    assert_eq!(approximate_french_number(999, &up), "des centaines");
+    assert_eq!(approximate_french_number(1_000, &up), "un millier");
+}
+
+#[test]
+fn test_environ() {
+    let options = ApproximateOptions::default();
+    assert_eq!(approximate_french_number(20, &options), "une vingtaine");
+    assert_eq!(approximate_french_number(23, &options), "une vingtaine");
+    assert_eq!(approximate_french_number(1_100, &options), "un millier");
+    assert_eq!(approximate_french_number(1_000_000, &options), "un million");
+    assert_eq!(
+        approximate_french_number(1_200_000, &options),
+        "environ un million"
+    );
+    assert_eq!(approximate_french_number(300, &options), "des centaines");
+    assert_eq!(approximate_french_number(5, &options), "cinq");
+    let collective = ApproximateOptions {
+        environ: EnvironPolicy::InexactCollective,
+        ..options
+    };
+    assert_eq!(approximate_french_number(20, &collective), "une vingtaine");
+    assert_eq!(
+        approximate_french_number(23, &collective),
+        "environ une vingtaine"
+    );
+    assert_eq!(
+        approximate_french_number(1_200_000, &collective),
+        "environ un million"
+    );
+    let always = ApproximateOptions {
+        environ: EnvironPolicy::Always,
+        ..options
+    };
+    assert_eq!(
+        approximate_french_number(10, &always),
+        "environ une dizaine"
+    );
+    assert_eq!(approximate_french_number(3_000, &always), "des milliers");
+    let several = ApproximateOptions {
+        several: true,
+        ..options
+    };
+    assert_eq!(
+        approximate_french_number(5_000_000_000, &several),
+        "plusieurs milliards"
+    );
+}
+
+#[test]
+fn test_spelling() {
+    let options = ApproximateOptions {
+        spelling: POST_REFORM_FEMININE,
+        ..ApproximateOptions::default()
+    };
+    assert_eq!(approximate_french_number(1, &options), "une");
+    assert_eq!(approximate_french_number(12, &options), "une douzaine");
+}
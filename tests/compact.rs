@@ -0,0 +1,146 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{compact_french_number, CompactOptions, Rounding, DOLLAR, EURO};
+
+fn words() -> CompactOptions {
+    CompactOptions::default()
+}
+
+fn abbreviated() -> CompactOptions {
+    CompactOptions {
+        abbreviated: true,
+        ..CompactOptions::default()
+    }
+}
+
+#[test]
+fn test_words() {
+    let options = words();
+    assert_eq!(compact_french_number(0, &options), "0");
+    assert_eq!(compact_french_number(950, &options), "950");
+    assert_eq!(compact_french_number(15_000, &options), "15\u{202f}000");
+    assert_eq!(compact_french_number(999_999, &options), "999\u{202f}999");
+    assert_eq!(compact_french_number(1_000_000, &options), "1 million");
+    assert_eq!(compact_french_number(1_940_000, &options), "1,9 million");
+    assert_eq!(compact_french_number(1_960_000, &options), "2 millions");
+    assert_eq!(compact_french_number(2_500_000, &options), "2,5 millions");
+    assert_eq!(compact_french_number(-2_500_000, &options), "-2,5 millions");
+    assert_eq!(compact_french_number(999_960_000, &options), "1 milliard");
+    assert_eq!(
+        compact_french_number(12_300_000_000, &options),
+        "12,3 milliards"
+    );
+    assert_eq!(compact_french_number(10i128.pow(12), &options), "1 billion");
+    assert_eq!(
+        compact_french_number(10i128.pow(15), &options),
+        "1 billiard"
+    );
+    assert_eq!(
+        compact_french_number(i128::MAX, &options),
+        "170,1 sextillions"
+    );
+    assert_eq!(
+        compact_french_number(i128::MIN, &options),
+        "-170,1 sextillions"
+    );
+}
+
+#[test]
+fn test_abbreviated() {
+    let options = abbreviated();
+    assert_eq!(compact_french_number(0, &options), "0");
+    assert_eq!(compact_french_number(500, &options), "500");
+    assert_eq!(compact_french_number(950, &options), "950");
+    assert_eq!(compact_french_number(1_000, &options), "1\u{a0}k");
+    assert_eq!(compact_french_number(15_000, &options), "15\u{a0}k");
+    assert_eq!(compact_french_number(999_999, &options), "1\u{a0}M");
+    assert_eq!(compact_french_number(2_500_000, &options), "2,5\u{a0}M");
+    assert_eq!(compact_french_number(3_000_000_000, &options), "3\u{a0}Md");
+    assert_eq!(
+        compact_french_number(4_500_000_000_000, &options),
+        "4\u{202f}500\u{a0}Md"
+    );
+}
+
+#[test]
+fn test_rounding() {
+    let options = |rounding| CompactOptions {
+        rounding,
+        ..words()
+    };
+    assert_eq!(
+        compact_french_number(1_250_000, &options(Rounding::Nearest)),
+        "1,3 million"
+    );
+    assert_eq!(
+        compact_french_number(1_249_999, &options(Rounding::Nearest)),
+        "1,2 million"
+    );
+    assert_eq!(
+        compact_french_number(1_299_999, &options(Rounding::Down)),
+        "1,2 million"
+    );
+    assert_eq!(
+        compact_french_number(1_200_001, &options(Rounding::Up)),
+        "1,3 million"
+    );
+    assert_eq!(
+        compact_french_number(-1_200_001, &options(Rounding::Up)),
+        "-1,3 million"
+    );
+    assert_eq!(
+        compact_french_number(1_999_999, &options(Rounding::Down)),
+        "1,9 million"
+    );
+    let precise = CompactOptions {
+        fraction_digits: 3,
+        ..words()
+    };
+    assert_eq!(compact_french_number(1_234_567, &precise), "1,235 million");
+    assert_eq!(compact_french_number(1_230_000, &precise), "1,23 million");
+    let integral = CompactOptions {
+        fraction_digits: 0,
+        ..words()
+    };
+    assert_eq!(compact_french_number(2_500_000, &integral), "3 millions");
+    let many = CompactOptions {
+        fraction_digits: 100,
+        ..abbreviated()
+    };
+    assert_eq!(compact_french_number(1_234, &many), "1,234\u{a0}k");
+}
+
+#[test]
+fn test_units() {
+    let euros = CompactOptions {
+        unit: Some(EURO),
+        ..words()
+    };
+    assert_eq!(compact_french_number(1, &euros), "1 euro");
+    assert_eq!(compact_french_number(15_000, &euros), "15\u{202f}000 euros");
+    assert_eq!(
+        compact_french_number(1_000_000, &euros),
+        "1 million d'euros"
+    );
+    assert_eq!(
+        compact_french_number(2_500_000, &euros),
+        "2,5 millions d'euros"
+    );
+    let dollars = CompactOptions {
+        unit: Some(DOLLAR),
+        ..words()
+    };
+    assert_eq!(
+        compact_french_number(1_234_567_890, &dollars),
+        "1,2 milliard de dollars"
+    );
+    let symbols = CompactOptions {
+        unit: Some(EURO),
+        ..abbreviated()
+    };
+    assert_eq!(compact_french_number(0, &symbols), "0\u{a0}€");
+    assert_eq!(compact_french_number(500, &symbols), "500\u{a0}€");
+    assert_eq!(compact_french_number(950, &symbols), "950\u{a0}€");
+    assert_eq!(compact_french_number(2_500_000, &symbols), "2,5\u{a0}M€");
+    assert_eq!(compact_french_number(3_000_000_000, &symbols), "3\u{a0}Md€");
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_decimal_digits, french_digits, DigitsOptions, EURO_DIGITS, FRENCH_DIGITS,
+    PERCENT_DIGITS, YEAR_DIGITS,
+};
+
+#[test]
+fn test_digits() {
+    assert_eq!(french_digits(&0, &FRENCH_DIGITS), "0");
+    assert_eq!(french_digits(&999, &FRENCH_DIGITS), "999");
+    assert_eq!(french_digits(&1000, &FRENCH_DIGITS), "1\u{202f}000");
+    assert_eq!(french_digits(&-12_345, &FRENCH_DIGITS), "-12\u{202f}345");
+    assert_eq!(
+        french_digits(&i128::MIN, &FRENCH_DIGITS).replace('\u{202f}', " "),
+        "-170 141 183 460 469 231 731 687 303 715 884 105 728"
+    );
+    assert_eq!(french_digits(&1990, &YEAR_DIGITS), "1990");
+    assert_eq!(french_digits(&10_000, &YEAR_DIGITS), "10\u{202f}000");
+    assert_eq!(french_digits(&50, &PERCENT_DIGITS), "50\u{a0}%");
+    let options = DigitsOptions {
+        group_separator: Some('.'),
+        unit: Some("€"),
+        unit_separator: None,
+        ..DigitsOptions::default()
+    };
+    assert_eq!(french_digits(&1_500_000u64, &options), "1.500.000€");
+}
+
+#[test]
+fn test_decimal_digits() {
+    assert_eq!(french_decimal_digits(0, 2, &FRENCH_DIGITS), "0,00");
+    assert_eq!(french_decimal_digits(-5, 2, &FRENCH_DIGITS), "-0,05");
+    assert_eq!(
+        french_decimal_digits(12_345, 0, &FRENCH_DIGITS),
+        "12\u{202f}345"
+    );
+    assert_eq!(
+        french_decimal_digits(1_234_550, 2, &EURO_DIGITS),
+        "12\u{202f}345,50\u{a0}€"
+    );
+    assert_eq!(
+        french_decimal_digits(
+            1_234_567_891,
+            4,
+            &DigitsOptions {
+                group_separator: None,
+                ..FRENCH_DIGITS
+            }
+        ),
+        "123456,7891"
+    );
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_digits_bigint() {
+    let n = num_bigint::BigInt::from(10).pow(40);
+    assert_eq!(
+        french_digits(&n, &FRENCH_DIGITS).replace('\u{202f}', " "),
+        "10 000 000 000 000 000 000 000 000 000 000 000 000 000"
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    explain_french_number, Rule, POST_REFORM_FEMININE, POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE,
+};
+
+fn rules(n: i64, options: &french_numbers::Options) -> Vec<(Rule, String)> {
+    let (text, explanations) = explain_french_number(&n, options).unwrap();
+    explanations
+        .into_iter()
+        .map(|e| (e.rule, text[e.text].to_owned()))
+        .collect()
+}
+
+#[test]
+fn test_explain() {
+    assert_eq!(rules(0, &POST_REFORM_MASCULINE), []);
+    assert_eq!(rules(17, &PRE_REFORM_MASCULINE), []);
+    assert_eq!(
+        rules(80, &POST_REFORM_MASCULINE),
+        [(Rule::QuatreVingtsPlural, "quatre-vingts".into())]
+    );
+    assert_eq!(
+        rules(-91, &PRE_REFORM_MASCULINE),
+        [
+            (Rule::QuatreVingtFollowed, "quatre-vingt".into()),
+            (Rule::NoEtAfterQuatreVingt, "onze".into()),
+        ]
+    );
+    assert_eq!(
+        rules(300_101, &PRE_REFORM_MASCULINE),
+        [
+            (Rule::CentBeforeMille, "cent".into()),
+            (Rule::MilleInvariable, "mille".into()),
+            (Rule::NoUnBeforeCent, "cent".into()),
+            (Rule::TraditionalHyphens, "trois cent mille cent un".into()),
+        ]
+    );
+    assert_eq!(
+        rules(-1_200_041, &POST_REFORM_FEMININE),
+        [
+            (Rule::UnBeforeScale, "million".into()),
+            (Rule::CentBeforeMille, "cent".into()),
+            (Rule::MilleInvariable, "mille".into()),
+            (Rule::Et, "et".into()),
+            (Rule::Feminine, "une".into()),
+            (
+                Rule::ReformHyphens,
+                "un-million-deux-cent-mille-quarante-et-une".into()
+            ),
+        ]
+    );
+    assert_eq!(
+        rules(200_001_000, &POST_REFORM_MASCULINE),
+        [
+            (Rule::CentPlural, "cents".into()),
+            (Rule::ScalePlural, "millions".into()),
+            (Rule::NoUnBeforeMille, "mille".into()),
+            (Rule::ReformHyphens, "deux-cents-millions-mille".into()),
+        ]
+    );
+    assert_eq!(
+        rules(81, &POST_REFORM_FEMININE),
+        [
+            (Rule::QuatreVingtFollowed, "quatre-vingt".into()),
+            (Rule::NoEtAfterQuatreVingt, "une".into()),
+            (Rule::Feminine, "une".into()),
+            (Rule::ReformHyphens, "quatre-vingt-une".into()),
+        ]
+    );
+    assert_eq!(
+        rules(21, &POST_REFORM_FEMININE),
+        [
+            (Rule::Et, "et".into()),
+            (Rule::Feminine, "une".into()),
+            (Rule::ReformHyphens, "vingt-et-une".into()),
+        ]
+    );
+}
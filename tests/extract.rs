@@ -0,0 +1,169 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    extract_french_numbers, NumberKind, POST_REFORM_MASCULINE, PRE_REFORM_FEMININE,
+    PRE_REFORM_MASCULINE,
+};
+
+fn extract(text: &str) -> Vec<(&str, NumberKind, i128, i128)> {
+    extract_french_numbers(text)
+        .into_iter()
+        .map(|n| (&text[n.span], n.kind, n.numerator, n.denominator))
+        .collect()
+}
+
+#[test]
+fn test_digits() {
+    use NumberKind::*;
+
+    assert_eq!(
+        extract("Il y avait 1 234 567 personnes et 12\u{202f}000,5 euros, soit -3,25 %."),
+        [
+            ("1 234 567", Cardinal, 1_234_567, 1),
+            ("12\u{202f}000,5", Decimal, 120_005, 10),
+            ("-3,25", Decimal, -325, 100),
+        ]
+    );
+    assert_eq!(
+        extract("En 2026 250 000 visiteurs, 2,5 millions de vues et 3 milliards d'euros."),
+        [
+            ("2026", Cardinal, 2026, 1),
+            ("250 000", Cardinal, 250_000, 1),
+            ("2,5 millions", Cardinal, 2_500_000, 1),
+            ("3 milliards", Cardinal, 3_000_000_000, 1),
+        ]
+    );
+    assert_eq!(
+        extract("Le 1er, la 1re, le 2nd, le 3e et le 20ème, mais pas 3D ni A320."),
+        [
+            ("1er", Ordinal, 1, 1),
+            ("1re", Ordinal, 1, 1),
+            ("2nd", Ordinal, 2, 1),
+            ("3e", Ordinal, 3, 1),
+            ("20ème", Ordinal, 20, 1),
+        ]
+    );
+    assert_eq!(
+        extract("Codes : 21/03/2026, 10:30, v1.2, 1,2,3, 10-12 et x-5."),
+        []
+    );
+    assert_eq!(
+        extract("1 2 3"),
+        [
+            ("1", Cardinal, 1, 1),
+            ("2", Cardinal, 2, 1),
+            ("3", Cardinal, 3, 1)
+        ]
+    );
+    // Numbers too large to fit are skipped as a whole.
+    let huge = format!("Il y a 1{} étoiles et 2 soleils.", " 000".repeat(13));
+    assert_eq!(extract(&huge), [("2", Cardinal, 2, 1)]);
+    assert_eq!(
+        extract("-170 141 183 460 469 231 731 687 303 715 884 105 729 ou 7"),
+        [("7", Cardinal, 7, 1)]
+    );
+}
+
+#[test]
+fn test_words() {
+    use NumberKind::*;
+
+    assert_eq!(
+        extract("Trois cent vingt et un élèves, deux-cents professeurs et mille chaises."),
+        [
+            ("Trois cent vingt et un", Cardinal, 321, 1),
+            ("deux-cents", Cardinal, 200, 1),
+            ("mille", Cardinal, 1000, 1),
+        ]
+    );
+    assert_eq!(
+        extract("Le vingt-et-unième siècle, la première fois, le deux centième jour."),
+        [
+            ("vingt-et-unième", Ordinal, 21, 1),
+            ("première", Ordinal, 1, 1),
+            ("deux centième", Ordinal, 200, 1),
+        ]
+    );
+    assert_eq!(
+        extract("Deux virgule cinq, moins trois virgule zéro quatorze et zéro virgule zéro."),
+        [
+            ("Deux virgule cinq", Decimal, 25, 10),
+            ("moins trois virgule zéro quatorze", Decimal, -3014, 1000),
+            ("zéro virgule zéro", Decimal, 0, 10),
+        ]
+    );
+    assert_eq!(
+        extract("Un quart, trois quarts, deux tiers, une demie et cinq centièmes."),
+        [
+            ("Un quart", Fraction, 1, 4),
+            ("trois quarts", Fraction, 3, 4),
+            ("deux tiers", Fraction, 2, 3),
+            ("une demie", Fraction, 1, 2),
+            ("cinq centièmes", Fraction, 5, 100),
+        ]
+    );
+    assert_eq!(
+        extract("Au moins deux, il fait moins cinq."),
+        [("deux", Cardinal, 2, 1), ("moins cinq", Cardinal, -5, 1)]
+    );
+    assert_eq!(
+        extract("Douze dollars et cinquante cents, vingts ou cents."),
+        [("Douze", Cardinal, 12, 1), ("cinquante", Cardinal, 50, 1)]
+    );
+    assert_eq!(
+        extract("Deux cents, quatre-vingts, six-vingts."),
+        [
+            ("Deux cents", Cardinal, 200, 1),
+            ("quatre-vingts", Cardinal, 80, 1),
+            ("six-vingts", Cardinal, 120, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_articles() {
+    use NumberKind::*;
+
+    assert_eq!(extract("Un chat et une souris."), []);
+    assert_eq!(
+        extract("Un ou deux chats, entre 3 et une souris, un sur dix."),
+        [
+            ("Un", Cardinal, 1, 1),
+            ("deux", Cardinal, 2, 1),
+            ("3", Cardinal, 3, 1),
+            ("une", Cardinal, 1, 1),
+            ("un", Cardinal, 1, 1),
+            ("dix", Cardinal, 10, 1),
+        ]
+    );
+    assert_eq!(
+        extract("Vingt et un chats et un million de souris."),
+        [
+            ("Vingt et un", Cardinal, 21, 1),
+            ("un million", Cardinal, 1_000_000, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_options() {
+    let options = |text| {
+        extract_french_numbers(text)
+            .into_iter()
+            .map(|n| n.options)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        options("deux cent un, vingt-et-une, trois cent vingt-deux, 12"),
+        [
+            Some(PRE_REFORM_MASCULINE),
+            Some(french_numbers::POST_REFORM_FEMININE),
+            Some(PRE_REFORM_MASCULINE),
+            None,
+        ]
+    );
+    assert_eq!(
+        options("vingt-deux, trente et une"),
+        [Some(POST_REFORM_MASCULINE), Some(PRE_REFORM_FEMININE)]
+    );
+}
@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(feature = "alloc")]
 
 use french_numbers::{
     french_number, french_number_options, Options, POST_REFORM_FEMININE, POST_REFORM_MASCULINE,
@@ -102,6 +102,36 @@ fn test_unreformed() {
     );
 }
 
+#[test]
+fn test_vigesimal() {
+    use french_numbers::{french_number_vigesimal, Vigesimal, PRE_REFORM_FEMININE};
+
+    let vigesimal =
+        |n: u64| french_number_vigesimal(&n, &POST_REFORM_MASCULINE, &Vigesimal::default());
+    assert_eq!(vigesimal(119), "cent-dix-neuf");
+    assert_eq!(vigesimal(120), "six-vingts");
+    assert_eq!(vigesimal(121), "six-vingt-un");
+    assert_eq!(vigesimal(131), "six-vingt-onze");
+    assert_eq!(vigesimal(140), "sept-vingts");
+    assert_eq!(vigesimal(157), "sept-vingt-dix-sept");
+    assert_eq!(vigesimal(300), "quinze-vingts");
+    assert_eq!(vigesimal(399), "dix-neuf-vingt-dix-neuf");
+    assert_eq!(vigesimal(400), "quatre-cents");
+    assert_eq!(vigesimal(1_300), "mille-quinze-vingts");
+    assert_eq!(vigesimal(120_000), "six-vingt-mille");
+    assert_eq!(vigesimal(140_000_000), "sept-vingts-millions");
+    let vigesimal = |n: u64| {
+        french_number_vigesimal(&n, &PRE_REFORM_FEMININE, &Vigesimal { min: 40, max: 399 })
+    };
+    assert_eq!(vigesimal(39), "trente-neuf");
+    assert_eq!(vigesimal(40), "deux-vingts");
+    assert_eq!(vigesimal(61), "trois-vingt-une");
+    assert_eq!(vigesimal(80), "quatre-vingts");
+    assert_eq!(vigesimal(91), "quatre-vingt-onze");
+    assert_eq!(vigesimal(100), "cinq-vingts");
+    assert_eq!(vigesimal(1_381), "mille dix-neuf-vingt-une");
+}
+
 #[test]
 fn test_podcastfrancaisfacile() {
     // From http://www.podcastfrancaisfacile.com/
@@ -266,3 +296,187 @@ fn test_termiumplus() {
         "dix-milliards-trois-cent-cinquante-millions"
     );
 }
+
+#[test]
+fn test_write_french_number() {
+    use french_numbers::write_french_number;
+
+    let mut s = String::new();
+    for n in [0, 1, 21, 80, 180, 200_080, 1_000_080, 37_251_061] {
+        s.clear();
+        write_french_number(&mut s, &n, &PRE_REFORM_MASCULINE).unwrap();
+        assert_eq!(s, french_number_options(&n, &PRE_REFORM_MASCULINE));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_french_number_io() {
+    use french_numbers::write_french_number_io;
+
+    for n in [0, 1, 21, 80, 180, 200_080, 1_000_080, 37_251_061] {
+        let mut out = Vec::new();
+        write_french_number_io(&mut out, &-n, &POST_REFORM_FEMININE).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            french_number_options(&-n, &POST_REFORM_FEMININE)
+        );
+    }
+
+    let mut too_small = [0u8; 8];
+    let err =
+        write_french_number_io(&mut &mut too_small[..], &71, &POST_REFORM_MASCULINE).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[test]
+fn test_display() {
+    use french_numbers::French;
+
+    assert_eq!(French(&0).to_string(), "zéro");
+    assert_eq!(format!("{}", French(&-71)), "moins soixante-et-onze");
+    assert_eq!(format!("{:#}", French(&-71)), "moins soixante et onze");
+    assert_eq!(format!("{:+}", French(&0)), "zéro");
+    assert_eq!(
+        format!("{:+#}", French(&200_001)),
+        "plus deux cent mille un"
+    );
+    assert_eq!(format!("{:<6}|", French(&2)), "deux  |");
+    assert_eq!(format!("{:>6}|", French(&2)), "  deux|");
+    assert_eq!(format!("{:-^7}|", French(&2)), "-deux--|");
+    assert_eq!(format!("{:3}|", French(&17)), "dix-sept|");
+    assert_eq!(format!("{:.3}|", French(&10)), "dix|");
+    assert_eq!(format!("{:>5.2}|", French(&1000)), "   mi|");
+    assert_eq!(format!("{:.3}|", French(&0)), "zér|");
+}
+
+#[test]
+fn test_ordinal() {
+    use french_numbers::{french_ordinal, french_ordinal_options, PRE_REFORM_FEMININE};
+
+    assert_eq!(french_ordinal(&1).as_deref(), Some("premier"));
+    assert_eq!(french_ordinal(&2).as_deref(), Some("deuxième"));
+    assert_eq!(french_ordinal(&71).as_deref(), Some("soixante-et-onzième"));
+    assert_eq!(french_ordinal(&81).as_deref(), Some("quatre-vingt-unième"));
+    assert_eq!(french_ordinal(&300).as_deref(), Some("trois-centième"));
+    assert_eq!(french_ordinal(&1000).as_deref(), Some("millième"));
+    assert_eq!(french_ordinal(&1009).as_deref(), Some("mille-neuvième"));
+    assert_eq!(french_ordinal(&1_000_000).as_deref(), Some("millionième"));
+    assert_eq!(
+        french_ordinal(&1_000_000_000).as_deref(),
+        Some("milliardième")
+    );
+    assert_eq!(
+        french_ordinal(&80_000_000).as_deref(),
+        Some("quatre-vingt-millionième")
+    );
+    assert_eq!(
+        french_ordinal(&200_000_000).as_deref(),
+        Some("deux-cent-millionième")
+    );
+    assert_eq!(
+        french_ordinal(&1_000_001).as_deref(),
+        Some("un-million-unième")
+    );
+    assert_eq!(
+        french_ordinal_options(&101, &PRE_REFORM_FEMININE).as_deref(),
+        Some("cent unième")
+    );
+    assert_eq!(french_ordinal(&0), None);
+    assert_eq!(french_ordinal(&-3), None);
+    let mut big = BigInt::one();
+    for _ in 1..103 {
+        big *= 10;
+    }
+    assert_eq!(french_ordinal(&big), None);
+}
+
+#[test]
+fn test_ordinal_adverb() {
+    use french_numbers::{
+        french_latin_ordinal_adverb, french_ordinal_adverb, POST_REFORM_MASCULINE,
+        PRE_REFORM_FEMININE,
+    };
+
+    let adverb = |n: i64| french_ordinal_adverb(&n, &POST_REFORM_MASCULINE);
+    assert_eq!(adverb(1).as_deref(), Some("premièrement"));
+    assert_eq!(adverb(2).as_deref(), Some("deuxièmement"));
+    assert_eq!(adverb(5).as_deref(), Some("cinquièmement"));
+    assert_eq!(adverb(9).as_deref(), Some("neuvièmement"));
+    assert_eq!(adverb(21).as_deref(), Some("vingt-et-unièmement"));
+    assert_eq!(adverb(80).as_deref(), Some("quatre-vingtièmement"));
+    assert_eq!(adverb(0), None);
+    assert_eq!(adverb(-1), None);
+    assert_eq!(
+        french_ordinal_adverb(&31, &PRE_REFORM_FEMININE).as_deref(),
+        Some("trente et unièmement")
+    );
+    let latin = [
+        "primo", "secundo", "tertio", "quarto", "quinto", "sexto", "septimo", "octavo", "nono",
+        "decimo",
+    ];
+    for (n, word) in (1..).zip(latin) {
+        assert_eq!(french_latin_ordinal_adverb(&n), Some(word));
+    }
+    assert_eq!(french_latin_ordinal_adverb(&0), None);
+    assert_eq!(french_latin_ordinal_adverb(&11), None);
+    assert_eq!(french_latin_ordinal_adverb(&-1), None);
+}
+
+#[test]
+fn test_to_french() {
+    use french_numbers::ToFrench;
+    use std::num::{NonZeroI8, NonZeroU32, Saturating, Wrapping};
+
+    assert_eq!(0u8.to_french(), "zéro");
+    assert_eq!(i8::MIN.to_french(), "moins cent-vingt-huit");
+    assert_eq!(u128::MAX.to_french(), french_number(&u128::MAX),);
+    assert_eq!(NonZeroU32::new(80).unwrap().to_french(), "quatre-vingts");
+    assert_eq!(
+        NonZeroI8::new(-1)
+            .unwrap()
+            .to_french_with(&POST_REFORM_FEMININE),
+        "moins une"
+    );
+    assert_eq!(Wrapping(21u16).to_french(), "vingt-et-un");
+    assert_eq!(
+        Saturating(21usize).to_french_ordinal().as_deref(),
+        Some("vingt-et-unième")
+    );
+    assert_eq!(
+        1.to_french_ordinal_with(&POST_REFORM_FEMININE).as_deref(),
+        Some("première")
+    );
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn test_to_french_bigint() {
+    use french_numbers::ToFrench;
+    use num_bigint::BigUint;
+
+    assert_eq!(BigInt::from(-80).to_french(), "moins quatre-vingts");
+    assert_eq!(
+        BigUint::from(2_000_000u32).to_french_ordinal().as_deref(),
+        Some("deux-millionième")
+    );
+}
+
+#[test]
+fn test_write_french_number_to_slice() {
+    use french_numbers::write_french_number_to_slice;
+
+    let mut buffer = [0; 64];
+    assert_eq!(
+        write_french_number_to_slice(&mut buffer, &1_021_001, &POST_REFORM_FEMININE),
+        Ok("un-million-vingt-et-un-mille-une")
+    );
+    assert_eq!(
+        write_french_number_to_slice(&mut buffer[..4], &0, &POST_REFORM_MASCULINE),
+        Err(std::fmt::Error)
+    );
+    assert_eq!(
+        write_french_number_to_slice(&mut buffer[..5], &0, &POST_REFORM_MASCULINE),
+        Ok("zéro")
+    );
+}
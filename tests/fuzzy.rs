@@ -1,10 +1,40 @@
+#![cfg(feature = "alloc")]
+
 // Patterns crashing the code found via fuzzing (cargo-fuzz)
 
 #[test]
 fn test_crash() {
     assert_eq!(
         french_numbers::french_number(&i128::MIN),
-        "-170141183460469231731687303715884105728"
+        "moins cent-soixante-dix-sextillions-cent-quarante-et-un-quintilliards-cent-quatre-vingt-trois-quintillions-quatre-cent-soixante-quadrilliards-quatre-cent-soixante-neuf-quadrillions-deux-cent-trente-et-un-trilliards-sept-cent-trente-et-un-trillions-six-cent-quatre-vingt-sept-billiards-trois-cent-trois-billions-sept-cent-quinze-milliards-huit-cent-quatre-vingt-quatre-millions-cent-cinq-mille-sept-cent-vingt-huit"
+    );
+    assert_eq!(
+        french_numbers::french_number(&i8::MIN),
+        "moins cent-vingt-huit"
+    );
+}
+
+#[test]
+fn test_bounded_minimums() {
+    use french_numbers::french_number;
+
+    assert_eq!(
+        french_number(&i16::MIN),
+        "moins trente-deux-mille-sept-cent-soixante-huit"
+    );
+    assert_eq!(
+        french_number(&i32::MIN),
+        "moins deux-milliards-cent-quarante-sept-millions-quatre-cent-quatre-vingt-trois-mille-six-cent-quarante-huit"
+    );
+    assert_eq!(
+        french_number(&i64::MIN),
+        format!(
+            "moins {}",
+            french_number(&(i64::MIN as i128).unsigned_abs())
+        )
+    );
+    assert_eq!(
+        french_number(&isize::MIN),
+        french_number(&(isize::MIN as i64))
     );
-    assert_eq!(french_numbers::french_number(&i8::MIN), "-128");
 }
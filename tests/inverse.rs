@@ -0,0 +1,86 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{inverse_normalize_french_text, CurrencyPlacement, InverseOptions};
+
+fn inverse(text: &str) -> String {
+    inverse_normalize_french_text(
+        text,
+        &InverseOptions {
+            group_separator: Some(' '),
+            threshold: 0,
+            ..InverseOptions::default()
+        },
+    )
+    .replace('\u{a0}', " ")
+}
+
+#[test]
+fn test_numbers() {
+    assert_eq!(inverse("vingt-deux mille trois cent un"), "22 301");
+    assert_eq!(inverse("moins cinq degrés"), "-5 degrés");
+    assert_eq!(inverse("deux virgule zéro cinq"), "2,05");
+    assert_eq!(inverse("le vingt-et-unième siècle"), "le 21e siècle");
+    assert_eq!(inverse("la première et les deuxièmes"), "la 1re et les 2es");
+    assert_eq!(
+        inverse("trois quarts des votants"),
+        "trois quarts des votants"
+    );
+    assert_eq!(
+        inverse("un chat et un ou deux chiens"),
+        "un chat et 1 ou 2 chiens"
+    );
+    assert_eq!(inverse("vingt pour cent"), "20 %");
+}
+
+#[test]
+fn test_dates() {
+    assert_eq!(
+        inverse("le premier mai deux mille vingt-six"),
+        "le 1er mai 2026"
+    );
+    assert_eq!(
+        inverse_normalize_french_text("le trois mars mille neuf cent", &InverseOptions::default()),
+        "le 3 mars 1900"
+    );
+}
+
+#[test]
+fn test_amounts() {
+    assert_eq!(inverse("douze euros cinquante"), "12,50 €");
+    assert_eq!(inverse("douze euros et cinquante centimes"), "12,50 €");
+    assert_eq!(inverse("douze dollars et cinquante cents"), "12,50 $");
+    assert_eq!(inverse("cinquante cents"), "0,50 $");
+    assert_eq!(inverse("trois centimes et deux euros"), "0,03 € et 2 €");
+    assert_eq!(inverse("douze cents dollars"), "1 200 $");
+    assert_eq!(inverse("trois cents euros"), "300 €");
+    assert_eq!(inverse("deux euros et trois chats"), "2 € et 3 chats");
+    assert_eq!(inverse("un euro cinq centimes"), "1,05 €");
+    assert_eq!(inverse("deux millions d'euros"), "2 000 000 €");
+    assert_eq!(inverse("trois virgule cinq dollars"), "3,5 $");
+    assert_eq!(
+        inverse_normalize_french_text(
+            "mille cinq cents dollars",
+            &InverseOptions {
+                currency_placement: CurrencyPlacement::Before,
+                ..InverseOptions::default()
+            }
+        ),
+        "$1\u{202f}500"
+    );
+}
+
+#[test]
+fn test_threshold() {
+    let options = InverseOptions {
+        group_separator: None,
+        threshold: 100,
+        ..InverseOptions::default()
+    };
+    assert_eq!(
+        inverse_normalize_french_text(
+            "quatre-vingt-dix-neuf moutons, cent vaches, trois pour cent et douze mille poules",
+            &options
+        ),
+        "quatre-vingt-dix-neuf moutons, 100 vaches, 3\u{a0}% et 12000 poules"
+    );
+}
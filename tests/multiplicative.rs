@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{french_collective, french_multiplicative};
+
+#[test]
+fn test_multiplicative() {
+    let expected = [
+        "simple",
+        "double",
+        "triple",
+        "quadruple",
+        "quintuple",
+        "sextuple",
+        "septuple",
+        "octuple",
+        "nonuple",
+        "décuple",
+    ];
+    for (n, word) in (1..).zip(expected) {
+        assert_eq!(french_multiplicative(&n), Some(word));
+    }
+    assert_eq!(french_multiplicative(&100u8), Some("centuple"));
+    assert_eq!(french_multiplicative(&0), None);
+    assert_eq!(french_multiplicative(&11), None);
+    assert_eq!(french_multiplicative(&1000), None);
+    assert_eq!(french_multiplicative(&-2), None);
+    assert_eq!(french_multiplicative(&u128::MAX), None);
+}
+
+#[test]
+fn test_collective() {
+    assert_eq!(french_collective(&2), Some("une paire"));
+    assert_eq!(french_collective(&6), Some("une demi-douzaine"));
+    assert_eq!(french_collective(&12u64), Some("une douzaine"));
+    assert_eq!(french_collective(&144), Some("une grosse"));
+    assert_eq!(french_collective(&0), None);
+    assert_eq!(french_collective(&1), None);
+    assert_eq!(french_collective(&10), None);
+    assert_eq!(french_collective(&-12), None);
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{normalize_french_text, POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE};
+
+fn normalize(text: &str) -> String {
+    normalize_french_text(text, &POST_REFORM_MASCULINE)
+}
+
+#[test]
+fn test_dates() {
+    assert_eq!(
+        normalize("le 1 mai 1990"),
+        "le premier mai mille-neuf-cent-quatre-vingt-dix"
+    );
+    assert_eq!(normalize("le 1er mai"), "le premier mai");
+    assert_eq!(
+        normalize("le 21/03/2026."),
+        "le vingt-et-un mars deux-mille-vingt-six."
+    );
+    assert_eq!(normalize("le 01/12/2000"), "le premier décembre deux-mille");
+    assert_eq!(normalize("le 32/03/2026"), "le 32/03/2026");
+    for impossible in [
+        "31/02/2026",
+        "30/02/2024",
+        "29/02/2023",
+        "31/04/2026",
+        "29/02/1900",
+    ] {
+        assert_eq!(normalize(impossible), impossible);
+    }
+    assert_eq!(
+        normalize("le 29/02/2024"),
+        "le vingt-neuf février deux-mille-vingt-quatre"
+    );
+    assert_eq!(
+        normalize("le 29/02/2000"),
+        "le vingt-neuf février deux-mille"
+    );
+}
+
+#[test]
+fn test_currencies() {
+    assert_eq!(normalize("12,50 €"), "douze euros cinquante");
+    assert_eq!(normalize("1 € et 1,5 €"), "un euro et un euro cinquante");
+    assert_eq!(normalize("0,01 €"), "un centime");
+    assert_eq!(normalize("0,99 EUR"), "quatre-vingt-dix-neuf centimes");
+    assert_eq!(normalize("2 000 000 €"), "deux-millions d'euros");
+    assert_eq!(
+        normalize("2,5 millions $"),
+        "deux virgule cinq millions de dollars"
+    );
+    assert_eq!(normalize("21 £"), "vingt-et-une livres");
+    assert_eq!(normalize("-3 CHF"), "moins trois francs");
+}
+
+#[test]
+fn test_readings() {
+    assert_eq!(normalize("3,25 % de 1 234,056"), "trois virgule vingt-cinq pour cent de mille-deux-cent-trente-quatre virgule zéro cinquante-six");
+    assert_eq!(
+        normalize("la 1re et les 2ndes"),
+        "la première et les secondes"
+    );
+    assert_eq!(
+        normalize("le 21e et les 3es"),
+        "le vingt-et-unième et les troisièmes"
+    );
+    assert_eq!(
+        normalize("31 jours et 31 semaines"),
+        "trente-et-un jours et trente-et-une semaines"
+    );
+    assert_eq!(
+        normalize_french_text("1 500 personnes", &PRE_REFORM_MASCULINE),
+        "mille cinq cents personnes"
+    );
+    assert_eq!(
+        normalize("12bis et 21 ter rue de la Paix"),
+        "douze bis et vingt-et-un ter rue de la Paix"
+    );
+}
+
+#[test]
+fn test_untouched() {
+    for text in [
+        "Un A320 décolle à 10:30.",
+        "Écrire à jean.dupont42@exemple.fr ou visiter www.exemple.fr/2026.",
+        "Voir https://exemple.fr/page?id=12 et le code 4F2A.",
+        "Aucun nombre ici.",
+        "Le modèle X12bis ou 12bisou.",
+        "Appeler le 01 23 45 67 89 ou le +33 6 12 34 56 78.",
+        "Le dossier 0612345678 et la pièce 007.",
+        "Il y a 1 000 000 000 000 000 000 000 000 000 000 000 000 000 000 étoiles.",
+    ] {
+        assert_eq!(normalize(text), text);
+    }
+}
+
+#[test]
+fn test_identifiers() {
+    assert_eq!(
+        normalize("Appeler le 01 23 45 67 89 avant 18 heures."),
+        "Appeler le 01 23 45 67 89 avant dix-huit heures."
+    );
+    assert_eq!(normalize("1 2 3"), "un deux trois");
+    assert_eq!(normalize("0,5 %"), "zéro virgule cinq pour cent");
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_decimal_number, parse_french_numeral, Decimal, NumeralError, POST_REFORM_FEMININE,
+    POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE,
+};
+
+fn parse(text: &str) -> Result<(i128, u32), NumeralError> {
+    parse_french_numeral(text).map(|d| (d.mantissa, d.scale))
+}
+
+#[test]
+fn test_parse_numeral() {
+    assert_eq!(parse("0"), Ok((0, 0)));
+    assert_eq!(parse("  42 "), Ok((42, 0)));
+    assert_eq!(parse("+12345678"), Ok((12_345_678, 0)));
+    assert_eq!(parse("1 234 567"), Ok((1_234_567, 0)));
+    assert_eq!(parse("1\u{a0}234\u{202f}567"), Ok((1_234_567, 0)));
+    assert_eq!(parse("−1\u{2009}234,5"), Ok((-12_345, 1)));
+    assert_eq!(parse("1.234.567,89"), Ok((123_456_789, 2)));
+    assert_eq!(parse("1'234'567.89"), Ok((123_456_789, 2)));
+    assert_eq!(parse("1’234"), Ok((1234, 0)));
+    assert_eq!(parse("1.234"), Ok((1234, 0)));
+    assert_eq!(parse("3.14"), Ok((314, 2)));
+    assert_eq!(parse("0,000 001"), Ok((1, 6)));
+    assert_eq!(parse("0,50"), Ok((50, 2)));
+}
+
+#[test]
+fn test_parse_numeral_errors() {
+    assert_eq!(parse(""), Err(NumeralError::Empty));
+    assert_eq!(parse(" - "), Err(NumeralError::Empty));
+    assert_eq!(parse("1 2345"), Err(NumeralError::InvalidGrouping(2..6)));
+    assert_eq!(parse("1234 567"), Err(NumeralError::InvalidGrouping(0..4)));
+    assert_eq!(parse("1 234.567"), Err(NumeralError::InvalidCharacter(5)));
+    assert_eq!(parse("1,2,3"), Err(NumeralError::InvalidCharacter(3)));
+    assert_eq!(parse("1,"), Err(NumeralError::InvalidCharacter(1)));
+    assert_eq!(parse("1 "), Ok((1, 0)));
+    assert_eq!(parse("1  234"), Err(NumeralError::InvalidCharacter(2)));
+    assert_eq!(parse("12a"), Err(NumeralError::InvalidCharacter(2)));
+    assert_eq!(
+        parse("0,123 4567"),
+        Err(NumeralError::InvalidGrouping(6..10))
+    );
+    assert_eq!(
+        parse("999 999 999 999 999 999 999 999 999 999 999 999 999"),
+        Err(NumeralError::Overflow)
+    );
+    assert_eq!("1 234,5".parse::<Decimal>(), Ok(Decimal::new(12_345, 1)));
+}
+
+#[test]
+fn test_decimal() {
+    assert_eq!(Decimal::new(12_345, 1).to_integer(), None);
+    assert_eq!(Decimal::new(12_300, 2).to_integer(), Some(123));
+    assert_eq!(Decimal::from(-7).to_integer(), Some(-7));
+    assert_eq!(
+        Decimal::new(-123_456_789, 2).to_string(),
+        "-1\u{202f}234\u{202f}567,89"
+    );
+}
+
+#[test]
+fn test_decimal_number() {
+    let spell =
+        |text, options| french_decimal_number(&parse_french_numeral(text).unwrap(), options);
+    assert_eq!(spell("0", &POST_REFORM_MASCULINE), "zéro");
+    assert_eq!(spell("1,5", &POST_REFORM_FEMININE), "une virgule cinq");
+    assert_eq!(
+        spell("21,01", &POST_REFORM_FEMININE),
+        "vingt-et-une virgule zéro un"
+    );
+    assert_eq!(
+        spell("-0,5", &POST_REFORM_MASCULINE),
+        "moins zéro virgule cinq"
+    );
+    assert_eq!(
+        spell("2,00", &POST_REFORM_MASCULINE),
+        "deux virgule zéro zéro"
+    );
+    assert_eq!(
+        spell("1.234,567", &PRE_REFORM_MASCULINE),
+        "mille deux cent trente-quatre virgule cinq cent soixante-sept"
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{analyze_orthography, Convention};
+
+#[test]
+fn test_conventions() {
+    use Convention::*;
+
+    let conventions = |text| {
+        let report = analyze_orthography(text);
+        assert_eq!(report.occurrences.len(), 1, "{text}");
+        report.occurrences[0].conventions.clone()
+    };
+    assert_eq!(conventions("vingt-deux"), []);
+    assert_eq!(conventions("Vingt et un"), [Traditional]);
+    assert_eq!(conventions("vingt-et-un"), [Reformed]);
+    assert_eq!(conventions("soixante-dix-sept"), [StandardTens]);
+    assert_eq!(conventions("quatre-vingts"), [StandardTens]);
+    assert_eq!(conventions("septante-sept"), [RegionalTens]);
+    assert_eq!(conventions("huitante"), [RegionalTens]);
+    assert_eq!(
+        conventions("mil neuf cent nonante"),
+        [Traditional, RegionalTens, Mil]
+    );
+    assert_eq!(
+        conventions("deux-mille-quatre-vingt-dix"),
+        [Reformed, StandardTens, Mille]
+    );
+    assert_eq!(conventions("deux cent-trois"), [Traditional, Reformed]);
+    assert_eq!(conventions("moins trois-cents"), [Reformed]);
+}
+
+#[test]
+fn test_report() {
+    let text = "En mil neuf cent nonante, trois cent vingt et un élèves et deux-cents \
+                professeurs, soit trois cent vingt-deux personnes en tout, et un chat.";
+    let report = analyze_orthography(text);
+    let found = report
+        .occurrences
+        .iter()
+        .map(|o| (&text[o.span.clone()], o.value))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            ("mil neuf cent nonante", 1990),
+            ("trois cent vingt et un", 321),
+            ("deux-cents", 200),
+            ("trois cent vingt-deux", 322),
+        ]
+    );
+    assert_eq!(
+        report.majority,
+        [
+            Convention::Traditional,
+            Convention::RegionalTens,
+            Convention::Mil
+        ]
+    );
+    assert_eq!(report.inconsistent, [2]);
+
+    let report = analyze_orthography("septante et soixante-dix, mais pas de nombre");
+    assert_eq!(report.majority, []);
+    assert_eq!(report.inconsistent, []);
+    assert!(analyze_orthography("aucun nombre ici")
+        .occurrences
+        .is_empty());
+}
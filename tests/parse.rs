@@ -0,0 +1,218 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    check_french_number, french_number_options, french_number_vigesimal, parse_french_number,
+    Diagnostic, DiagnosticKind, ParseError, Vigesimal, POST_REFORM_FEMININE, POST_REFORM_MASCULINE,
+    PRE_REFORM_MASCULINE,
+};
+
+#[test]
+fn test_parse_round_trip() {
+    for n in (-1000..20_000).chain((0..2000).map(|i| i * 7_919_993_177)) {
+        for options in [
+            &POST_REFORM_MASCULINE,
+            &PRE_REFORM_MASCULINE,
+            &POST_REFORM_FEMININE,
+        ] {
+            let text = french_number_options(&n, options);
+            assert_eq!(parse_french_number(&text), Ok(n), "{text}");
+        }
+        let vigesimal = Vigesimal { min: 40, max: 399 };
+        let text = french_number_vigesimal(&n, &PRE_REFORM_MASCULINE, &vigesimal);
+        assert_eq!(parse_french_number(&text), Ok(n), "{text}");
+    }
+    assert_eq!(
+        parse_french_number(&french_number_options(&i128::MIN, &POST_REFORM_MASCULINE)),
+        Ok(i128::MIN)
+    );
+}
+
+#[test]
+fn test_parse_variants() {
+    assert_eq!(parse_french_number("  Soixante et onze "), Ok(71));
+    assert_eq!(parse_french_number("septante-et-un"), Ok(71));
+    assert_eq!(parse_french_number("huitante"), Ok(80));
+    assert_eq!(parse_french_number("octante-deux"), Ok(82));
+    assert_eq!(parse_french_number("nonante-neuf"), Ok(99));
+    assert_eq!(parse_french_number("mil neuf cent"), Ok(1900));
+    assert_eq!(parse_french_number("quatre-vingt"), Ok(80));
+    assert_eq!(parse_french_number("deux-cents-mille"), Ok(200_000));
+    assert_eq!(parse_french_number("vingt-et-deux"), Ok(22));
+    assert_eq!(parse_french_number("un mille"), Ok(1000));
+    assert_eq!(parse_french_number("six-vingts"), Ok(120));
+    assert_eq!(parse_french_number("Quinze-Vingts"), Ok(300));
+    assert_eq!(parse_french_number("sept vingt dix-sept"), Ok(157));
+    assert_eq!(parse_french_number("dix-neuf-vingt-onze"), Ok(391));
+    assert_eq!(parse_french_number("mille six-vingt-un"), Ok(1121));
+    assert_eq!(parse_french_number("six-vingt-mille"), Ok(120_000));
+    assert_eq!(parse_french_number("douze cents"), Ok(1200));
+    assert_eq!(
+        parse_french_number("dix-neuf cent quatre-vingt-quatre"),
+        Ok(1984)
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(parse_french_number(""), Err(ParseError::Empty));
+    assert_eq!(parse_french_number(" - "), Err(ParseError::Empty));
+    assert_eq!(
+        parse_french_number("deux pommes"),
+        Err(ParseError::UnknownWord(5..11))
+    );
+    assert_eq!(
+        parse_french_number("vingt-onze"),
+        Err(ParseError::UnexpectedWord(6..10))
+    );
+    assert_eq!(
+        parse_french_number("dix-deux"),
+        Err(ParseError::UnexpectedWord(4..8))
+    );
+    assert_eq!(
+        parse_french_number("un million deux millions"),
+        Err(ParseError::UnexpectedWord(16..24))
+    );
+    assert_eq!(
+        parse_french_number("mille mille"),
+        Err(ParseError::UnexpectedWord(6..11))
+    );
+    assert_eq!(
+        parse_french_number("zéro un"),
+        Err(ParseError::UnexpectedWord(6..8))
+    );
+    assert_eq!(
+        parse_french_number("vingt et"),
+        Err(ParseError::UnexpectedWord(6..8))
+    );
+    assert_eq!(
+        parse_french_number("cent six-vingts"),
+        Err(ParseError::UnexpectedWord(9..15))
+    );
+    assert_eq!(
+        parse_french_number("six-vingt-trente"),
+        Err(ParseError::UnexpectedWord(10..16))
+    );
+    assert_eq!(
+        parse_french_number("mille douze cents"),
+        Err(ParseError::UnexpectedWord(12..17))
+    );
+    assert_eq!(
+        parse_french_number("moins"),
+        Err(ParseError::UnexpectedWord(0..5))
+    );
+    assert_eq!(
+        parse_french_number("un sextilliard"),
+        Err(ParseError::Overflow)
+    );
+}
+
+#[test]
+fn test_parse_overflow() {
+    let text = french_number_options(&u128::MAX, &POST_REFORM_MASCULINE);
+    let past_max = text.strip_suffix("cinq").unwrap().to_owned() + "six";
+    assert_eq!(parse_french_number(&past_max), Err(ParseError::Overflow));
+    let text = french_number_options(&i128::MIN, &POST_REFORM_MASCULINE);
+    let past_min = text.strip_suffix("huit").unwrap().to_owned() + "neuf";
+    assert_eq!(parse_french_number(&past_min), Err(ParseError::Overflow));
+}
+
+fn diagnostic(kind: DiagnosticKind, span: std::ops::Range<usize>, replacement: &str) -> Diagnostic {
+    Diagnostic {
+        kind,
+        span,
+        replacement: replacement.to_owned(),
+    }
+}
+
+#[test]
+fn test_check() {
+    use DiagnosticKind::*;
+
+    assert_eq!(
+        check_french_number("quatre-vingts", &POST_REFORM_MASCULINE),
+        Ok(vec![])
+    );
+    assert_eq!(
+        check_french_number("Quatre-vingt", &POST_REFORM_MASCULINE),
+        Ok(vec![diagnostic(MissingPlural, 7..12, "vingts")])
+    );
+    assert_eq!(
+        check_french_number("deux-cents-mille", &POST_REFORM_MASCULINE),
+        Ok(vec![diagnostic(InvalidPlural, 5..10, "cent")])
+    );
+    assert_eq!(
+        check_french_number("deux milles", &PRE_REFORM_MASCULINE),
+        Ok(vec![diagnostic(InvalidPlural, 5..11, "mille")])
+    );
+    assert_eq!(
+        check_french_number("vingt-et-deux", &POST_REFORM_MASCULINE),
+        Ok(vec![diagnostic(UnexpectedEt, 5..9, "-")])
+    );
+    assert_eq!(
+        check_french_number("vingt-un", &PRE_REFORM_MASCULINE),
+        Ok(vec![diagnostic(MissingEt, 5..6, " et ")])
+    );
+    assert_eq!(
+        check_french_number("trois cent vingt", &POST_REFORM_MASCULINE),
+        Ok(vec![
+            diagnostic(WrongJoiner, 5..6, "-"),
+            diagnostic(WrongJoiner, 10..11, "-"),
+        ])
+    );
+    assert_eq!(
+        check_french_number("trois-cent-vingt", &PRE_REFORM_MASCULINE),
+        Ok(vec![
+            diagnostic(WrongJoiner, 5..6, " "),
+            diagnostic(WrongJoiner, 10..11, " "),
+        ])
+    );
+    assert_eq!(
+        check_french_number("un mille un", &PRE_REFORM_MASCULINE),
+        Ok(vec![diagnostic(UnexpectedWord, 0..3, "")])
+    );
+    assert_eq!(
+        check_french_number("septante", &PRE_REFORM_MASCULINE),
+        Ok(vec![diagnostic(Misspelling, 0..8, "soixante-dix")])
+    );
+    assert_eq!(
+        check_french_number("vingt et une", &PRE_REFORM_MASCULINE),
+        Ok(vec![diagnostic(Misspelling, 9..12, "un")])
+    );
+    assert_eq!(
+        check_french_number("pommes", &PRE_REFORM_MASCULINE),
+        Err(ParseError::UnknownWord(0..6))
+    );
+}
+
+#[test]
+fn test_canonicalize() {
+    use french_numbers::canonicalize_french_number;
+
+    let canonical = |text, options| canonicalize_french_number(text, options).unwrap();
+    assert_eq!(
+        canonical("trois cent mille deux cents", &POST_REFORM_MASCULINE),
+        "trois-cent-mille-deux-cents"
+    );
+    assert_eq!(
+        canonical("trois-cent-mille-deux-cents", &PRE_REFORM_MASCULINE),
+        "trois cent mille deux cents"
+    );
+    assert_eq!(
+        canonical("\tQuatre-vingt dix-sept  ", &PRE_REFORM_MASCULINE),
+        "\tQuatre-vingt-dix-sept  "
+    );
+    assert_eq!(
+        canonical("Nonante Et Un", &POST_REFORM_MASCULINE),
+        "Quatre-Vingt-Onze"
+    );
+    assert_eq!(canonical("MIL", &POST_REFORM_MASCULINE), "MILLE");
+    assert_eq!(canonical("Un", &POST_REFORM_FEMININE), "Une");
+    assert_eq!(
+        canonical("moins Deux millions", &POST_REFORM_MASCULINE),
+        "moins deux-millions"
+    );
+    assert_eq!(
+        canonicalize_french_number("  ", &POST_REFORM_MASCULINE),
+        Err(ParseError::Empty)
+    );
+}
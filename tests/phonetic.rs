@@ -0,0 +1,125 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_number_ipa, french_number_pronunciation, NextPhoneme, POST_REFORM_FEMININE,
+    POST_REFORM_MASCULINE,
+};
+
+fn ipa(n: i64) -> String {
+    french_number_ipa(&n, &POST_REFORM_MASCULINE, None).unwrap()
+}
+
+fn before(n: i64, noun: &str) -> String {
+    french_number_ipa(&n, &POST_REFORM_MASCULINE, Some(noun)).unwrap()
+}
+
+#[test]
+fn test_ipa() {
+    assert_eq!(ipa(0), "ze.ʁo");
+    assert_eq!(ipa(1), "œ̃");
+    assert_eq!(ipa(4), "katʁ");
+    assert_eq!(ipa(17), "di.sɛt");
+    assert_eq!(ipa(18), "di.zɥit");
+    assert_eq!(ipa(19), "diz.nœf");
+    assert_eq!(ipa(20), "vɛ̃");
+    assert_eq!(ipa(21), "vɛ̃.te.œ̃");
+    assert_eq!(ipa(22), "vɛ̃t.dø");
+    assert_eq!(ipa(29), "vɛ̃t.nœf");
+    assert_eq!(ipa(71), "swa.sɑ̃.te.ɔ̃z");
+    assert_eq!(ipa(80), "ka.tʁə.vɛ̃");
+    assert_eq!(ipa(81), "ka.tʁə.vɛ̃.œ̃");
+    assert_eq!(ipa(99), "ka.tʁə.vɛ̃.diz.nœf");
+    assert_eq!(ipa(101), "sɑ̃.œ̃");
+    assert_eq!(ipa(122), "sɑ̃.vɛ̃t.dø");
+    assert_eq!(ipa(-21), "mwɛ̃.vɛ̃.te.œ̃");
+    assert_eq!(ipa(2_000_000), "dø.mi.ljɔ̃");
+    assert_eq!(ipa(3_000_000_000), "tʁwa.mi.ljaʁ");
+    assert_eq!(
+        french_number_ipa(&41, &POST_REFORM_FEMININE, None).as_deref(),
+        Some("ka.ʁɑ̃.te.yn")
+    );
+    assert_eq!(
+        french_number_ipa(&u128::MAX, &POST_REFORM_MASCULINE, None).map(|s| s.is_empty()),
+        Some(false)
+    );
+}
+
+#[test]
+fn test_final_consonants() {
+    assert_eq!(ipa(5), "sɛ̃k");
+    assert_eq!(ipa(6), "sis");
+    assert_eq!(ipa(8), "ɥit");
+    assert_eq!(ipa(10), "dis");
+    assert_eq!(ipa(500), "sɛ̃.sɑ̃");
+    assert_eq!(ipa(600), "si.sɑ̃");
+    assert_eq!(ipa(800), "ɥi.sɑ̃");
+    assert_eq!(ipa(10_000), "di.mil");
+    assert_eq!(ipa(6_000_000), "si.mi.ljɔ̃");
+    assert_eq!(ipa(108), "sɑ̃.ɥit");
+}
+
+#[test]
+fn test_liaison() {
+    assert_eq!(before(1, "an"), "œ̃n‿");
+    assert_eq!(before(2, "ans"), "døz‿");
+    assert_eq!(before(2, "livres"), "dø");
+    assert_eq!(before(6, "euros"), "siz‿");
+    assert_eq!(before(6, "livres"), "si");
+    assert_eq!(before(8, "jours"), "ɥi");
+    assert_eq!(before(9, "ans"), "nœv‿");
+    assert_eq!(before(9, "euros"), "nœf‿");
+    assert_eq!(before(10, "heures"), "diz‿");
+    assert_eq!(before(10, "héros"), "di");
+    assert_eq!(before(20, "ans"), "vɛ̃t‿");
+    assert_eq!(before(80, "ans"), "ka.tʁə.vɛ̃z‿");
+    assert_eq!(before(100, "ans"), "sɑ̃t‿");
+    assert_eq!(before(300, "euros"), "tʁwa.sɑ̃z‿");
+    assert_eq!(before(1000, "ans"), "mil‿");
+    assert_eq!(before(1000, "livres"), "mil");
+}
+
+fn variant(n: i64, next: NextPhoneme) -> (String, Option<char>) {
+    let p = french_number_pronunciation(&n, &POST_REFORM_MASCULINE, next).unwrap();
+    (p.ipa, p.liaison)
+}
+
+#[test]
+fn test_pronunciation() {
+    use NextPhoneme::*;
+    assert_eq!(variant(6, Pause), ("sis".into(), None));
+    assert_eq!(variant(6, Consonant), ("si".into(), None));
+    assert_eq!(variant(6, Vowel), ("siz".into(), Some('z')));
+    assert_eq!(variant(10, Pause), ("dis".into(), None));
+    assert_eq!(variant(10, Consonant), ("di".into(), None));
+    assert_eq!(variant(10, Vowel), ("diz".into(), Some('z')));
+    assert_eq!(variant(8, Pause), ("ɥit".into(), None));
+    assert_eq!(variant(8, Consonant), ("ɥi".into(), None));
+    assert_eq!(variant(8, Vowel), ("ɥit".into(), None));
+    assert_eq!(variant(5, Consonant), ("sɛ̃".into(), None));
+    assert_eq!(variant(5, Vowel), ("sɛ̃k".into(), None));
+    assert_eq!(variant(100, Pause), ("sɑ̃".into(), None));
+    assert_eq!(variant(100, Vowel), ("sɑ̃t".into(), Some('t')));
+    assert_eq!(variant(1, Vowel), ("œ̃n".into(), Some('n')));
+    assert_eq!(variant(20, Vowel), ("vɛ̃t".into(), Some('t')));
+    assert_eq!(variant(80, Vowel), ("ka.tʁə.vɛ̃z".into(), Some('z')));
+    assert_eq!(variant(26, Vowel), ("vɛ̃t.siz".into(), Some('z')));
+    assert_eq!(variant(2_000_000, Vowel), ("dø.mi.ljɔ̃z".into(), Some('z')));
+    assert_eq!(variant(1000, Vowel), ("mil".into(), None));
+    assert_eq!(variant(9, Vowel), ("nœf".into(), None));
+}
+
+#[test]
+fn test_next_phoneme() {
+    assert_eq!(NextPhoneme::default(), NextPhoneme::Pause);
+    assert_eq!(NextPhoneme::of_word("enfants"), NextPhoneme::Vowel);
+    assert_eq!(NextPhoneme::of_word("Ans"), NextPhoneme::Vowel);
+    assert_eq!(NextPhoneme::of_word("livres"), NextPhoneme::Consonant);
+    assert_eq!(NextPhoneme::of_word("hommes"), NextPhoneme::Vowel);
+    assert_eq!(NextPhoneme::of_word("haricots"), NextPhoneme::Consonant);
+    let cent =
+        french_number_pronunciation(&100, &POST_REFORM_MASCULINE, NextPhoneme::of_word("ans"));
+    assert_eq!(cent.unwrap().to_string(), "sɑ̃t‿");
+    let huit =
+        french_number_pronunciation(&8, &POST_REFORM_MASCULINE, NextPhoneme::of_word("jours"));
+    assert_eq!(huit.unwrap().to_string(), "ɥi");
+}
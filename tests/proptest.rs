@@ -1,4 +1,8 @@
-use french_numbers::{french_number, french_number_options, Options};
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_number, french_number_options, french_number_vigesimal, Options, Vigesimal,
+};
 use proptest::prelude::*;
 
 proptest! {
@@ -48,3 +52,20 @@ proptest! {
         }
     }
 }
+
+proptest! {
+    #[test]
+    fn parse_round_trip(i in i128::MIN..i128::MAX, feminine: bool, reformed: bool) {
+        let options = Options { feminine, reformed };
+        assert_eq!(french_numbers::parse_french_number(&french_number_options(&i, &options)), Ok(i));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_vigesimal_round_trip(i in i128::MIN..i128::MAX, feminine: bool, reformed: bool) {
+        let options = Options { feminine, reformed };
+        let text = french_number_vigesimal(&i, &options, &Vigesimal::default());
+        assert_eq!(french_numbers::parse_french_number(&text), Ok(i));
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_roman_number, parse_roman_numeral, roman_numeral, NumeralError, RomanContext,
+    POST_REFORM_FEMININE, POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE,
+};
+
+#[test]
+fn test_roman_numeral() {
+    assert_eq!(roman_numeral(1).as_deref(), Some("I"));
+    assert_eq!(roman_numeral(4).as_deref(), Some("IV"));
+    assert_eq!(roman_numeral(9).as_deref(), Some("IX"));
+    assert_eq!(roman_numeral(40).as_deref(), Some("XL"));
+    assert_eq!(roman_numeral(444).as_deref(), Some("CDXLIV"));
+    assert_eq!(roman_numeral(1515).as_deref(), Some("MDXV"));
+    assert_eq!(roman_numeral(3999).as_deref(), Some("MMMCMXCIX"));
+    assert_eq!(roman_numeral(0), None);
+    assert_eq!(roman_numeral(4000), None);
+}
+
+#[test]
+fn test_parse_roman_numeral() {
+    for n in 1..4000 {
+        let roman = roman_numeral(n).unwrap();
+        assert_eq!(parse_roman_numeral(&roman), Ok(n));
+        assert_eq!(parse_roman_numeral(&roman.to_lowercase()), Ok(n));
+    }
+    assert_eq!(parse_roman_numeral(""), Err(NumeralError::Empty));
+    assert_eq!(
+        parse_roman_numeral("A"),
+        Err(NumeralError::InvalidCharacter(0))
+    );
+    assert_eq!(
+        parse_roman_numeral("IC"),
+        Err(NumeralError::InvalidCharacter(1))
+    );
+    assert_eq!(
+        parse_roman_numeral("VX"),
+        Err(NumeralError::InvalidCharacter(1))
+    );
+    assert_eq!(
+        parse_roman_numeral("XIIII"),
+        Err(NumeralError::InvalidCharacter(4))
+    );
+    assert_eq!(
+        parse_roman_numeral("MMMM"),
+        Err(NumeralError::InvalidCharacter(3))
+    );
+    assert_eq!(
+        parse_roman_numeral("XIV "),
+        Err(NumeralError::InvalidCharacter(3))
+    );
+}
+
+#[test]
+fn test_regnal() {
+    let regnal = |text| french_roman_number(text, RomanContext::Regnal, &POST_REFORM_MASCULINE);
+    assert_eq!(regnal("XIV").as_deref(), Ok("quatorze"));
+    assert_eq!(regnal("Ier").as_deref(), Ok("premier"));
+    assert_eq!(regnal("I").as_deref(), Ok("premier"));
+    assert_eq!(regnal("Ire").as_deref(), Ok("première"));
+    assert_eq!(regnal("II").as_deref(), Ok("deux"));
+    assert_eq!(regnal("XXIII").as_deref(), Ok("vingt-trois"));
+    assert_eq!(regnal("IIe"), Err(NumeralError::InvalidCharacter(2)));
+    assert_eq!(regnal("IVer"), Err(NumeralError::InvalidCharacter(2)));
+    assert_eq!(
+        french_roman_number("I", RomanContext::Regnal, &POST_REFORM_FEMININE).as_deref(),
+        Ok("première")
+    );
+}
+
+#[test]
+fn test_century() {
+    let century = |text| french_roman_number(text, RomanContext::Century, &POST_REFORM_MASCULINE);
+    assert_eq!(century("XXIe").as_deref(), Ok("vingt-et-unième"));
+    assert_eq!(century("XXIème").as_deref(), Ok("vingt-et-unième"));
+    assert_eq!(century("XIXᵉ").as_deref(), Ok("dix-neuvième"));
+    assert_eq!(century("Ier").as_deref(), Ok("premier"));
+    assert_eq!(century("Ière").as_deref(), Ok("première"));
+    assert_eq!(century("V").as_deref(), Ok("cinquième"));
+    assert_eq!(century("Ie"), Err(NumeralError::InvalidCharacter(1)));
+    assert_eq!(century("XXIes"), Err(NumeralError::InvalidCharacter(3)));
+    assert_eq!(
+        french_roman_number("XXIe", RomanContext::Century, &PRE_REFORM_MASCULINE).as_deref(),
+        Ok("vingt et unième")
+    );
+}
+
+#[test]
+fn test_plain() {
+    let plain = |text| french_roman_number(text, RomanContext::Plain, &POST_REFORM_MASCULINE);
+    assert_eq!(plain("I").as_deref(), Ok("un"));
+    assert_eq!(plain("MMXXVI").as_deref(), Ok("deux-mille-vingt-six"));
+    assert_eq!(plain("Ier"), Err(NumeralError::InvalidCharacter(1)));
+    assert_eq!(plain("XXIe"), Err(NumeralError::InvalidCharacter(3)));
+}
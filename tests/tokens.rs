@@ -0,0 +1,178 @@
+#![cfg(feature = "alloc")]
+
+use french_numbers::{
+    french_number_options, french_tokens, render_tokens, Joiner, TokenKind, POST_REFORM_FEMININE,
+    POST_REFORM_MASCULINE, PRE_REFORM_MASCULINE,
+};
+
+fn words(n: i64) -> Vec<(String, TokenKind, Joiner, std::ops::Range<usize>)> {
+    french_tokens(&n, &PRE_REFORM_MASCULINE)
+        .unwrap()
+        .into_iter()
+        .map(|t| (t.to_string(), t.kind, t.joiner, t.digits))
+        .collect()
+}
+
+#[test]
+fn test_tokens() {
+    use Joiner::{Hyphen, None, Space};
+    use TokenKind::*;
+
+    assert_eq!(words(0), [("zéro".into(), Unit, None, 0..1)]);
+    assert_eq!(
+        words(-71),
+        [
+            ("moins".into(), Sign, None, 0..0),
+            ("soixante".into(), Tens, Space, 0..1),
+            ("et".into(), Et, Space, 1..1),
+            ("onze".into(), Teen, Space, 1..2),
+        ]
+    );
+    assert_eq!(
+        words(37_251_061),
+        [
+            ("trente".into(), Tens, None, 0..1),
+            ("sept".into(), Unit, Hyphen, 1..2),
+            ("millions".into(), Scale, Space, 0..2),
+            ("deux".into(), Unit, Space, 2..3),
+            ("cent".into(), Hundred, Space, 2..3),
+            ("cinquante".into(), Tens, Space, 3..4),
+            ("et".into(), Et, Space, 4..4),
+            ("un".into(), Unit, Space, 4..5),
+            ("mille".into(), Thousand, Space, 2..5),
+            ("soixante".into(), Tens, Space, 6..7),
+            ("et".into(), Et, Space, 7..7),
+            ("un".into(), Unit, Space, 7..8),
+        ]
+    );
+    assert_eq!(
+        words(2_005_080),
+        [
+            ("deux".into(), Unit, None, 0..1),
+            ("millions".into(), Scale, Space, 0..1),
+            ("cinq".into(), Unit, Space, 3..4),
+            ("mille".into(), Thousand, Space, 1..4),
+            ("quatre-vingts".into(), Tens, Space, 5..6),
+        ]
+    );
+}
+
+#[test]
+fn test_plural_tokens() {
+    let tokens = french_tokens(&280_000_200u32, &POST_REFORM_MASCULINE).unwrap();
+    let plurals = tokens
+        .iter()
+        .filter(|t| t.is_plural())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(plurals, ["quatre-vingts", "millions", "cents"]);
+}
+
+#[test]
+fn test_render_tokens() {
+    for n in [
+        0, 1, 21, 71, 81, 91, 100, 180, 1_000, 1_081, 80_000, 1_000_000, 21_000_021,
+    ] {
+        for options in [&POST_REFORM_FEMININE, &PRE_REFORM_MASCULINE] {
+            let tokens = french_tokens(&-n, options).unwrap();
+            assert_eq!(render_tokens(&tokens), french_number_options(&-n, options));
+        }
+    }
+    assert!(french_tokens(&u128::MAX, &POST_REFORM_MASCULINE).is_some());
+    let mut big = num_bigint::BigInt::from(1);
+    for _ in 1..103 {
+        big *= 10;
+    }
+    assert_eq!(french_tokens(&big, &POST_REFORM_MASCULINE), Option::None);
+}
+
+#[test]
+fn test_alignment() {
+    use french_numbers::french_alignment;
+
+    let check = |n: i64, options, expected: &[(&str, &str)]| {
+        let (text, words) = french_alignment(&n, options).unwrap();
+        assert_eq!(text, french_number_options(&n, options));
+        let digits = n.unsigned_abs().to_string();
+        let pairs = words
+            .iter()
+            .map(|w| (&text[w.text.clone()], &digits[w.digits.clone()]))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected);
+    };
+    check(
+        37_251_061,
+        &POST_REFORM_MASCULINE,
+        &[
+            ("trente", "3"),
+            ("sept", "7"),
+            ("millions", "37"),
+            ("deux-cent", "2"),
+            ("cinquante", "5"),
+            ("et-un", "1"),
+            ("mille", "251"),
+            ("soixante", "6"),
+            ("et-un", "1"),
+        ],
+    );
+    check(
+        -1_000_096,
+        &PRE_REFORM_MASCULINE,
+        &[
+            ("moins", ""),
+            ("un", "1"),
+            ("million", "1"),
+            ("quatre-vingt", "9"),
+            ("seize", "6"),
+        ],
+    );
+    check(
+        71,
+        &POST_REFORM_MASCULINE,
+        &[("soixante", "7"), ("et-onze", "1")],
+    );
+    check(
+        -71,
+        &PRE_REFORM_MASCULINE,
+        &[("moins", ""), ("soixante", "7"), ("et onze", "1")],
+    );
+    check(
+        21,
+        &POST_REFORM_MASCULINE,
+        &[("vingt", "2"), ("et-un", "1")],
+    );
+    check(
+        21_021,
+        &PRE_REFORM_MASCULINE,
+        &[
+            ("vingt", "2"),
+            ("et un", "1"),
+            ("mille", "21"),
+            ("vingt", "2"),
+            ("et un", "1"),
+        ],
+    );
+    check(
+        92,
+        &POST_REFORM_MASCULINE,
+        &[("quatre-vingt", "9"), ("douze", "2")],
+    );
+    // Every non-zero digit is covered by exactly one word besides `mille`
+    // and the scale nouns.
+    for n in [21, 71, 79, 91, 99, 171, 2_071, 21_091, 71_000_081] {
+        let (text, words) = french_alignment(&n, &POST_REFORM_MASCULINE).unwrap();
+        let digits = n.to_string();
+        let mut covered = vec![0; digits.len()];
+        for w in words {
+            let word = &text[w.text];
+            if word != "mille" && !word.starts_with("million") {
+                for d in w.digits {
+                    covered[d] += 1;
+                }
+            }
+        }
+        for (digit, count) in digits.bytes().zip(covered) {
+            assert_eq!(count, usize::from(digit != b'0'), "{n}");
+        }
+    }
+}